@@ -0,0 +1,33 @@
+//! Application info commands
+//! 应用程序信息命令
+
+use tauri::AppHandle;
+
+use crate::models::app_info::AppInfo;
+use crate::models::error::ERROR_CODES;
+use crate::utils::storage::storage_paths;
+
+/// Returns version and environment information for the About dialog
+/// 返回用于“关于”对话框的版本和环境信息
+#[tauri::command]
+pub fn get_app_info(app_handle: AppHandle) -> AppInfo {
+    let storage = storage_paths();
+    AppInfo {
+        version: app_handle.package_info().version.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        webview_version: tauri::webview_version().ok(),
+        portable: storage.portable,
+        config_dir: storage.config_dir.display().to_string(),
+        data_dir: storage.data_dir.display().to_string(),
+        log_dir: storage.log_dir.display().to_string(),
+        cache_dir: storage.cache_dir.display().to_string(),
+    }
+}
+
+/// Lists every error code commands can return, so the frontend can check its translations
+/// 列出命令可能返回的所有错误代码，以便前端检查其翻译
+#[tauri::command]
+pub fn get_error_codes() -> Vec<&'static str> {
+    ERROR_CODES.to_vec()
+}
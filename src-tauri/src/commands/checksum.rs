@@ -0,0 +1,83 @@
+//! Checksum verification commands
+//! 校验和验证命令
+
+use std::io;
+
+use tauri::{AppHandle, Manager};
+
+use crate::models::checksum::{ChecksumResult, ChecksumVerdict};
+use crate::models::error::AppError;
+use crate::models::history::{HistoryEntry, OperationOutcome};
+use crate::services::operations::ActiveOperations;
+use crate::services::taskbar::TaskbarProgress;
+use crate::services::{checksum, history, notifications};
+use crate::utils::paths;
+use crate::utils::progress::OperationTimer;
+use crate::utils::time;
+
+/// Verifies an archive against a `.sha256`, `.sha1`, `.md5` or `SHA256SUMS`-style file next to it
+/// 使用同目录下的 `.sha256`、`.sha1`、`.md5` 或 `SHA256SUMS` 风格的文件验证压缩包
+///
+/// Progress is reported through `checksum-progress` events while hashing.
+/// 计算摘要期间通过 `checksum-progress` 事件报告进度。
+///
+/// # Arguments
+/// * `archive_path` - Archive to verify
+///   要验证的压缩包
+#[tauri::command]
+pub async fn verify_sidecar_checksum(
+    app_handle: AppHandle,
+    archive_path: String,
+) -> Result<ChecksumResult, AppError> {
+    let timer = OperationTimer::start();
+    let archive = paths::resolve_user_path("archive_path", &archive_path, None)?;
+    let archive_path = archive.display().to_string();
+    let path = archive.clone();
+    let operation_id = uuid::Uuid::new_v4().to_string();
+    let _operation = app_handle
+        .state::<ActiveOperations>()
+        .begin(&operation_id, "verify");
+    let app = app_handle.clone();
+    let id = operation_id.clone();
+    let mut result =
+        tauri::async_runtime::spawn_blocking(move || checksum::verify_sidecar(&app, &archive, &id))
+            .await
+            .map_err(|e| AppError::io(&path, io::Error::other(e)))?;
+    let hashed_bytes = match &result {
+        Ok(result) if result.actual.is_some() => {
+            path.metadata().ok().map(|metadata| metadata.len())
+        }
+        _ => None,
+    };
+    let metrics = timer.finish(hashed_bytes, None);
+    if let Ok(result) = &mut result {
+        result.metrics = Some(metrics);
+    }
+
+    let (outcome, message) = match &result {
+        Ok(result) if result.verdict == ChecksumVerdict::Mismatched => (
+            OperationOutcome::Warning,
+            Some("checksum mismatch".to_string()),
+        ),
+        Ok(_) => (OperationOutcome::Success, None),
+        Err(e) => (OperationOutcome::Failure, Some(e.to_string())),
+    };
+    let elapsed = timer.elapsed();
+    app_handle
+        .state::<TaskbarProgress>()
+        .finish(&app_handle, &operation_id, outcome);
+    notifications::notify_completion(&app_handle, &path, outcome, elapsed);
+    history::record(
+        &app_handle,
+        HistoryEntry {
+            timestamp: time::unix_now(),
+            kind: "verify".to_string(),
+            archive_path,
+            destination: None,
+            outcome,
+            message,
+            metrics,
+        },
+    );
+    result
+}
@@ -0,0 +1,96 @@
+//! Clipboard commands
+//! 剪贴板命令
+
+use std::path::{Component, Path};
+
+use tauri::State;
+
+use crate::models::dropped_files::DroppedItem;
+use crate::models::error::AppError;
+use crate::services::settings::SettingsStore;
+use crate::services::{clipboard, destination, dropped_files};
+use crate::utils::paths;
+
+/// Largest text placed on the clipboard
+/// 放入剪贴板的最大文本长度
+const MAX_CLIPBOARD_TEXT_BYTES: usize = 1024 * 1024;
+
+/// Copies entry paths to the clipboard, one per line
+/// 将条目路径复制到剪贴板，每行一个
+///
+/// # Arguments
+/// * `paths` - Archive-internal entry paths, `/`-separated
+///   压缩包内部的条目路径，以 `/` 分隔
+/// * `as_absolute` - Copy the paths the entries would get when extracted to the default destination
+///   复制条目解压到默认目标位置后将得到的路径
+/// * `archive_path` - Archive the entries belong to, required with `as_absolute`
+///   条目所属的压缩包，设置 `as_absolute` 时必填
+#[tauri::command]
+pub fn copy_paths_to_clipboard(
+    paths: Vec<String>,
+    as_absolute: bool,
+    archive_path: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<(), AppError> {
+    if paths.is_empty() {
+        return Err(AppError::InvalidArgument {
+            name: "paths".to_string(),
+            message: "no paths to copy".to_string(),
+        });
+    }
+
+    let lines: Vec<String> = if as_absolute {
+        let archive_path = archive_path.ok_or_else(|| AppError::InvalidArgument {
+            name: "archive_path".to_string(),
+            message: "required when as_absolute is set".to_string(),
+        })?;
+        let archive = paths::resolve_user_path("archive_path", &archive_path, None)?;
+        let base = destination::default_destination(&settings.get(), &archive);
+        paths
+            .iter()
+            .map(|path| {
+                let mut absolute = base.clone();
+                absolute.extend(path.split(['/', '\\']).filter(|part| is_plain_name(part)));
+                absolute.display().to_string()
+            })
+            .collect()
+    } else {
+        paths
+    };
+
+    let text = lines.join("\n");
+    if text.len() > MAX_CLIPBOARD_TEXT_BYTES {
+        return Err(AppError::InvalidArgument {
+            name: "paths".to_string(),
+            message: format!("more than {MAX_CLIPBOARD_TEXT_BYTES} bytes of text"),
+        });
+    }
+
+    clipboard::write_text(text)
+}
+
+/// Returns the files and folders copied in the system file manager, classified like dropped files
+/// 返回在系统文件管理器中复制的文件和文件夹，分类方式与拖放文件相同
+///
+/// A clipboard holding no file references yields an empty list, so the
+/// frontend can simply disable its paste and open menu items.
+/// 剪贴板中没有文件引用时返回空列表，前端可以据此直接禁用粘贴和打开菜单项。
+#[tauri::command]
+pub fn get_clipboard_files() -> Result<Vec<DroppedItem>, AppError> {
+    let paths = clipboard::read_file_list()?;
+    Ok(dropped_files::classify(&paths).items)
+}
+
+/// Whether `part` is a single ordinary name, so joining it can't leave the base folder
+/// `part` 是否为单个普通名称，从而拼接后不会离开基础文件夹
+///
+/// Drops `.`, `..`, empty parts and anything the platform reads as a root or
+/// drive prefix, the same components the extractor refuses to write through.
+/// 丢弃 `.`、`..`、空片段以及平台视为根目录或驱动器前缀的内容，与解压程序拒绝写入的组件相同。
+fn is_plain_name(part: &str) -> bool {
+    let mut components = Path::new(part).components();
+    matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    )
+}
@@ -0,0 +1,109 @@
+//! Extraction destination commands
+//! 解压目标命令
+
+use std::path::PathBuf;
+
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::services::destination;
+use crate::services::settings::SettingsStore;
+use crate::utils::{archive_format, paths};
+
+/// Returns the folder an archive should be extracted to by default
+/// 返回压缩包默认应解压到的文件夹
+///
+/// Follows the `destination_mode` setting so the frontend doesn't duplicate the logic.
+/// 遵循 `destination_mode` 设置，前端无需重复实现该逻辑。
+#[tauri::command]
+pub fn get_default_extract_destination(
+    archive_path: String,
+    settings: State<'_, SettingsStore>,
+) -> Result<String, AppError> {
+    let archive = paths::resolve_user_path("archive_path", &archive_path, None)?;
+    Ok(destination::default_destination(&settings.get(), &archive)
+        .display()
+        .to_string())
+}
+
+/// Resolves an output folder typed by the user to the absolute path extraction will use
+/// 将用户输入的输出文件夹解析为解压将使用的绝对路径
+///
+/// `~` expands to the home folder and relative paths resolve against the archive's folder.
+/// `~` 展开为主文件夹，相对路径基于压缩包所在文件夹解析。
+///
+/// # Arguments
+/// * `archive_path` - Archive about to be extracted
+///   即将解压的压缩包
+/// * `output_dir` - Folder as typed, e.g. `out/` or `~/Downloads`
+///   输入的文件夹，例如 `out/` 或 `~/Downloads`
+#[tauri::command]
+pub fn resolve_output_directory(
+    archive_path: String,
+    output_dir: String,
+) -> Result<String, AppError> {
+    let output = resolve_output(&archive_path, &output_dir)?;
+    Ok(output.display().to_string())
+}
+
+/// Resolves, creates and write-tests an output folder before extraction starts
+/// 在解压开始前解析、创建并测试输出文件夹是否可写
+///
+/// Lets a permission problem show up immediately instead of partway through the operation.
+/// 让权限问题立即显现，而不是在操作进行到一半时才出现。
+///
+/// # Arguments
+/// * `archive_path` - Archive about to be extracted
+///   即将解压的压缩包
+/// * `output_dir` - Folder as typed, resolved like `resolve_output_directory`
+///   输入的文件夹，解析方式与 `resolve_output_directory` 相同
+#[tauri::command]
+pub fn prepare_output_directory(
+    archive_path: String,
+    output_dir: String,
+) -> Result<String, AppError> {
+    let output = resolve_output(&archive_path, &output_dir)?;
+    destination::prepare_output_directory(&output)?;
+    Ok(output.display().to_string())
+}
+
+/// Creates the folder the extract-to-subfolder mode extracts into, named after the archive
+/// 创建“解压到子文件夹”模式使用的文件夹，以压缩包命名
+///
+/// A non-empty folder of that name is never merged into; `photos (2)` and so on
+/// are used instead.
+/// 不会合并到同名的非空文件夹中，而是改用 `photos (2)` 等名称。
+///
+/// # Arguments
+/// * `archive_path` - Archive about to be extracted
+///   即将解压的压缩包
+/// * `output_dir` - Folder to create the subfolder in, the default destination when omitted
+///   在其中创建子文件夹的文件夹，省略时使用默认解压目标
+///
+/// # Returns
+/// The folder actually created or reused
+/// 实际创建或复用的文件夹
+#[tauri::command]
+pub fn create_extract_subfolder(
+    archive_path: String,
+    output_dir: Option<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<String, AppError> {
+    let archive = paths::resolve_user_path("archive_path", &archive_path, None)?;
+    let parent = match output_dir {
+        Some(output_dir) => resolve_output(&archive_path, &output_dir)?,
+        None => destination::default_destination(&settings.get(), &archive),
+    };
+    let name = archive_format::archive_stem(&archive).ok_or_else(|| AppError::InvalidArgument {
+        name: "archive_path".to_string(),
+        message: "has no file name".to_string(),
+    })?;
+    let folder = destination::create_unique_folder(&parent, &name)?;
+    Ok(folder.display().to_string())
+}
+
+fn resolve_output(archive_path: &str, output_dir: &str) -> Result<PathBuf, AppError> {
+    let archive = paths::resolve_user_path("archive_path", archive_path, None)?;
+    let base = archive.parent().unwrap_or(&archive);
+    paths::resolve_user_path("output_dir", output_dir, Some(base))
+}
@@ -0,0 +1,39 @@
+//! Runtime diagnostics commands
+//! 运行时诊断命令
+
+use tauri::{AppHandle, Manager, State};
+
+use crate::models::diagnostics::{RuntimeDiagnostics, StagingDirUsage};
+use crate::services::open_requests::OpenRequestQueue;
+use crate::services::operations::ActiveOperations;
+use crate::services::settings::SettingsStore;
+use crate::services::window_manager::WindowArchives;
+use crate::utils::temp_dir;
+
+/// Summarizes the backend's managed state for the hidden diagnostics panel
+/// 为隐藏的诊断面板汇总后端的托管状态
+///
+/// Only the staging folders SoarZip created are walked on disk.
+/// 只会遍历 SoarZip 自己创建的暂存文件夹。
+#[tauri::command]
+pub fn get_runtime_diagnostics(
+    app_handle: AppHandle,
+    settings: State<'_, SettingsStore>,
+    operations: State<'_, ActiveOperations>,
+    windows: State<'_, WindowArchives>,
+    open_requests: State<'_, OpenRequestQueue>,
+) -> RuntimeDiagnostics {
+    RuntimeDiagnostics {
+        active_operations: operations.list(),
+        staging_dirs: temp_dir::op_dir_usage(&settings.get())
+            .into_iter()
+            .map(|(path, bytes)| StagingDirUsage {
+                path: path.display().to_string(),
+                bytes,
+            })
+            .collect(),
+        window_count: app_handle.webview_windows().len(),
+        pending_window_archives: windows.pending_count(),
+        queued_open_requests: open_requests.pending_count(),
+    }
+}
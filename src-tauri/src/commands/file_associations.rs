@@ -0,0 +1,64 @@
+//! File association commands
+//! 文件关联命令
+
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::models::file_associations::AssociationResult;
+use crate::services::file_associations;
+use crate::services::settings::SettingsStore;
+
+/// Makes SoarZip the handler for the given archive extensions (Windows only)
+/// 将 SoarZip 设为指定压缩包扩展名的处理程序（仅限 Windows）
+///
+/// # Arguments
+/// * `extensions` - Extensions such as "zip" or ".7z"
+///   扩展名，例如 "zip" 或 ".7z"
+#[tauri::command]
+pub fn register_file_associations(
+    extensions: Vec<String>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<AssociationResult>, AppError> {
+    let mut normalized = Vec::with_capacity(extensions.len());
+    for extension in &extensions {
+        match file_associations::normalize_extension(extension) {
+            Some(extension) => normalized.push(extension),
+            None => {
+                return Err(AppError::InvalidArgument {
+                    name: "extensions".to_string(),
+                    message: format!("invalid extension {extension}"),
+                });
+            }
+        }
+    }
+
+    let results = file_associations::register(&normalized)?;
+    settings.update_with(|settings| {
+        for result in results.iter().filter(|result| result.success) {
+            if !settings.registered_associations.contains(&result.extension) {
+                settings
+                    .registered_associations
+                    .push(result.extension.clone());
+            }
+        }
+    })?;
+    Ok(results)
+}
+
+/// Removes every association previously registered by SoarZip (Windows only)
+/// 移除 SoarZip 之前注册的所有关联（仅限 Windows）
+#[tauri::command]
+pub fn unregister_file_associations(
+    settings: State<'_, SettingsStore>,
+) -> Result<Vec<AssociationResult>, AppError> {
+    let registered = settings.get().registered_associations;
+    let results = file_associations::unregister(&registered)?;
+    settings.update_with(|settings| {
+        settings.registered_associations.retain(|extension| {
+            results
+                .iter()
+                .any(|result| &result.extension == extension && !result.success)
+        });
+    })?;
+    Ok(results)
+}
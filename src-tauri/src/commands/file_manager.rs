@@ -0,0 +1,30 @@
+//! File manager commands
+//! 文件管理器命令
+
+use std::io;
+
+use tauri::{AppHandle, Manager};
+
+use crate::models::error::AppError;
+use crate::services::file_manager::{self, RevealDebouncer};
+use crate::utils::paths;
+
+/// Reveals a file or folder in the system file manager
+/// 在系统文件管理器中显示文件或文件夹
+///
+/// Used for "Show archive in folder" and "Show extracted files".
+/// 用于“在文件夹中显示压缩包”和“显示解压的文件”。
+///
+/// # Arguments
+/// * `path` - File to select, or directory to open
+///   要选中的文件，或要打开的目录
+#[tauri::command]
+pub async fn show_in_folder(app_handle: AppHandle, path: String) -> Result<(), AppError> {
+    let path = paths::resolve_user_path("path", &path, None)?;
+    let target = path.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        file_manager::reveal_in_file_manager(&target, &app_handle.state::<RevealDebouncer>())
+    })
+    .await
+    .map_err(|e| AppError::io(&path, io::Error::other(e)))?
+}
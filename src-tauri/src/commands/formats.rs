@@ -0,0 +1,46 @@
+//! Archive format commands
+//! 压缩格式命令
+
+use crate::models::error::AppError;
+use crate::models::formats::{ArchiveHealth, SupportedExtension};
+use crate::utils::archive_format::{self, SUPPORTED_EXTENSIONS};
+use crate::utils::{archive_health, paths};
+
+/// Returns every extension the app can open, for building file dialog filters
+/// 返回应用可以打开的所有扩展名，用于构建文件对话框的过滤器
+///
+/// The `creatable` and `writable` flags let the frontend limit the create
+/// dialog and disable editing for formats 7-Zip can't write.
+/// `creatable` 和 `writable` 标志让前端可以限制创建对话框中的格式，并为 7-Zip 无法写入的格式禁用编辑功能。
+#[tauri::command]
+pub fn get_supported_extensions() -> Vec<SupportedExtension> {
+    SUPPORTED_EXTENSIONS
+        .iter()
+        .map(|(extension, format)| SupportedExtension {
+            extension: extension.to_string(),
+            format: format.to_string(),
+            creatable: archive_format::is_creatable_format(format),
+            writable: archive_format::is_writable_format(format),
+        })
+        .collect()
+}
+
+/// Checks from the file's header and trailer whether an archive looks truncated
+/// 根据文件的头部和尾部检查压缩包是否看起来不完整
+///
+/// A `truncated` verdict is a warning only; 7-Zip can often still list such files.
+/// `truncated` 结论仅作为警告；7-Zip 通常仍能列出此类文件的内容。
+///
+/// # Arguments
+/// * `path` - Archive to check
+///   要检查的压缩包
+#[tauri::command]
+pub fn quick_check_archive(path: String) -> Result<ArchiveHealth, AppError> {
+    let path = paths::resolve_user_path("path", &path, None)?;
+    if !path.is_file() {
+        return Err(AppError::NotFound {
+            path: path.display().to_string(),
+        });
+    }
+    archive_health::quick_check(&path).map_err(|e| AppError::io(&path, e))
+}
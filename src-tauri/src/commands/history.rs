@@ -0,0 +1,39 @@
+//! Operation history commands
+//! 操作历史命令
+
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::models::history::{HistoryEntry, HistoryFilter};
+use crate::services::history::OperationHistory;
+
+/// Default number of entries returned by `get_operation_history`
+/// `get_operation_history` 默认返回的条目数
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// Returns finished operations, newest first
+/// 返回已完成的操作，最新的在前
+///
+/// # Arguments
+/// * `filter` - Optional kind, archive, start time and outcome criteria
+///   可选的类型、压缩包、起始时间和结束方式筛选条件
+/// * `limit` - Maximum number of entries, defaults to 100
+///   条目的最大数量，默认为 100
+#[tauri::command]
+pub fn get_operation_history(
+    filter: Option<HistoryFilter>,
+    limit: Option<usize>,
+    history: State<'_, OperationHistory>,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    history.list(
+        &filter.unwrap_or_default(),
+        limit.unwrap_or(DEFAULT_HISTORY_LIMIT),
+    )
+}
+
+/// Deletes the whole operation history
+/// 删除全部操作历史
+#[tauri::command]
+pub fn clear_operation_history(history: State<'_, OperationHistory>) -> Result<(), AppError> {
+    history.clear()
+}
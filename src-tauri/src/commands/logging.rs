@@ -0,0 +1,78 @@
+//! Logging commands
+//! 日志命令
+
+use std::io;
+
+use tauri::{AppHandle, Manager, State};
+
+use crate::models::error::AppError;
+use crate::models::log::LogEntry;
+use crate::services::file_manager::{self, RevealDebouncer};
+use crate::services::settings::SettingsStore;
+use crate::utils::logger::{self, Level, log_info};
+
+/// Returns the path of the active log file, for support requests
+/// 返回当前日志文件的路径，用于技术支持
+#[tauri::command]
+pub fn get_log_file_path() -> Option<String> {
+    logger::log_file_path().map(|path| path.display().to_string())
+}
+
+/// Reveals the active log file in the system file manager
+/// 在系统文件管理器中显示当前日志文件
+#[tauri::command]
+pub async fn open_log_folder(app_handle: AppHandle) -> Result<(), AppError> {
+    let path = logger::log_file_path().ok_or_else(|| AppError::NotFound {
+        path: logger::LOG_FILE_NAME.to_string(),
+    })?;
+    let target = path.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        file_manager::reveal_in_file_manager(&target, &app_handle.state::<RevealDebouncer>())
+    })
+    .await
+    .map_err(|e| AppError::io(&path, io::Error::other(e)))?
+}
+
+/// Number of records returned when the caller gives no limit
+/// 调用方未指定数量时返回的记录数
+const DEFAULT_LOG_ENTRY_LIMIT: usize = 200;
+
+/// Returns the newest log records, oldest first, for an error dialog's log tail
+/// 返回最新的日志记录（按时间从旧到新），用于错误对话框中的日志尾部
+///
+/// # Arguments
+/// * `operation_id` - Only return records of this operation
+///   仅返回此操作的记录
+/// * `limit` - Maximum number of records, 200 by default
+///   记录的最大数量，默认为 200
+#[tauri::command]
+pub fn get_recent_log_entries(
+    operation_id: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<LogEntry>, AppError> {
+    let limit = limit.unwrap_or(DEFAULT_LOG_ENTRY_LIMIT);
+    logger::recent_entries(operation_id.as_deref(), limit).map_err(|e| {
+        let path = logger::log_file_path().unwrap_or_else(|| logger::LOG_FILE_NAME.into());
+        AppError::io(&path, e)
+    })
+}
+
+/// Returns the minimum level currently written to the log
+/// 返回当前写入日志的最低级别
+#[tauri::command]
+pub fn get_log_level() -> Level {
+    logger::level()
+}
+
+/// Changes the log level immediately and persists it
+/// 立即修改日志级别并持久化
+///
+/// # Arguments
+/// * `level` - "debug" for verbose logging, or "info", "warn", "error"
+///   "debug" 表示详细日志，或 "info"、"warn"、"error"
+#[tauri::command]
+pub fn set_log_level(level: Level, settings: State<'_, SettingsStore>) -> Result<(), AppError> {
+    logger::set_level(level);
+    log_info!("Log level set to {level}");
+    settings.update_with(|settings| settings.log_level = level)
+}
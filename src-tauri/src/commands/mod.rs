@@ -0,0 +1,21 @@
+//! Tauri command handlers exposed to the frontend
+//! 暴露给前端的 Tauri 命令处理程序
+
+pub mod app_info;
+pub mod checksum;
+pub mod clipboard;
+pub mod destination;
+pub mod diagnostics;
+pub mod file_associations;
+pub mod file_manager;
+pub mod formats;
+pub mod history;
+pub mod logging;
+pub mod open_requests;
+pub mod profiles;
+pub mod recent;
+pub mod settings;
+pub mod staging;
+pub mod updates;
+pub mod volumes;
+pub mod window;
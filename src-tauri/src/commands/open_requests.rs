@@ -0,0 +1,23 @@
+//! Archive open request commands
+//! 压缩包打开请求命令
+
+use tauri::{AppHandle, State};
+
+use crate::services::open_requests::OpenRequestQueue;
+
+/// Signals that the main window can receive `open-archive-request` events
+/// 通知主窗口已可以接收 `open-archive-request` 事件
+///
+/// Any archive passed on the command line is delivered right after this call.
+/// 调用后会立即投递通过命令行传入的压缩包。
+#[tauri::command]
+pub fn frontend_ready(app_handle: AppHandle, queue: State<'_, OpenRequestQueue>) {
+    queue.mark_ready(&app_handle);
+}
+
+/// Returns the next archive queued from a multi-file launch, if any
+/// 返回多文件启动时排队的下一个压缩包（如有）
+#[tauri::command]
+pub fn next_open_request(queue: State<'_, OpenRequestQueue>) -> Option<String> {
+    queue.take_next()
+}
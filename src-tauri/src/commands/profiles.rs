@@ -0,0 +1,40 @@
+//! Compression profile commands
+//! 压缩配置方案命令
+
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::models::profiles::{CompressionProfile, ProfileEntry};
+use crate::services::profiles;
+use crate::services::settings::SettingsStore;
+
+/// Returns the built-in profiles followed by the user's
+/// 返回内置配置方案，随后是用户配置方案
+#[tauri::command]
+pub fn list_profiles(settings: State<'_, SettingsStore>) -> Vec<ProfileEntry> {
+    profiles::list(&settings)
+}
+
+/// Creates or replaces a user profile
+/// 创建或替换用户配置方案
+///
+/// # Arguments
+/// * `name` - Profile name; built-in names are rejected
+///   配置方案名称；内置名称会被拒绝
+/// * `profile` - Format and options to store
+///   要保存的格式和选项
+#[tauri::command]
+pub fn save_profile(
+    name: String,
+    profile: CompressionProfile,
+    settings: State<'_, SettingsStore>,
+) -> Result<(), AppError> {
+    profiles::save(&settings, &name, profile)
+}
+
+/// Deletes a user profile
+/// 删除用户配置方案
+#[tauri::command]
+pub fn delete_profile(name: String, settings: State<'_, SettingsStore>) -> Result<(), AppError> {
+    profiles::delete(&settings, &name)
+}
@@ -0,0 +1,80 @@
+//! Recent archives commands
+//! 最近打开的压缩包命令
+
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::models::recent::RecentArchivesList;
+use crate::services::recent::RecentArchives;
+use crate::utils::paths;
+
+/// Default number of entries returned by `get_recent_archives`
+/// `get_recent_archives` 默认返回的条目数
+const DEFAULT_RECENT_LIMIT: usize = 10;
+
+/// Records an archive as opened just now
+/// 将压缩包记录为刚刚打开
+///
+/// Called at the end of a successful archive open.
+/// 在成功打开压缩包后调用。
+#[tauri::command]
+pub fn add_recent_archive(path: String, recent: State<'_, RecentArchives>) -> Result<(), AppError> {
+    recent.add(&resolve(&path)?)
+}
+
+/// Returns the pinned archives and the recent archives, most recent first
+/// 返回固定的压缩包和最近打开的压缩包，最近的在前
+///
+/// # Arguments
+/// * `limit` - Maximum number of unpinned entries, defaults to 10
+///   未固定条目的最大数量，默认为 10
+#[tauri::command]
+pub fn get_recent_archives(
+    limit: Option<usize>,
+    recent: State<'_, RecentArchives>,
+) -> Result<RecentArchivesList, AppError> {
+    recent.list(limit.unwrap_or(DEFAULT_RECENT_LIMIT))
+}
+
+/// Removes one archive from the recent list
+/// 从最近列表中移除一个压缩包
+#[tauri::command]
+pub fn remove_recent_archive(
+    path: String,
+    recent: State<'_, RecentArchives>,
+) -> Result<(), AppError> {
+    recent.remove(&resolve(&path)?)
+}
+
+/// Pins or unpins an archive, adding it to the list if needed
+/// 固定或取消固定压缩包，必要时将其添加到列表
+#[tauri::command]
+pub fn pin_recent_archive(
+    path: String,
+    pinned: bool,
+    recent: State<'_, RecentArchives>,
+) -> Result<(), AppError> {
+    recent.set_pinned(&resolve(&path)?, pinned)
+}
+
+/// Clears the recent list
+/// 清空最近列表
+///
+/// # Arguments
+/// * `including_pinned` - Also remove pinned entries, defaults to false
+///   同时移除固定条目，默认为 false
+#[tauri::command]
+pub fn clear_recent_archives(
+    including_pinned: Option<bool>,
+    recent: State<'_, RecentArchives>,
+) -> Result<(), AppError> {
+    recent.clear(including_pinned.unwrap_or(false))
+}
+
+/// Normalizes `path` so one archive always maps to the same recent entry
+/// 规范化 `path`，使同一个压缩包始终对应同一条最近记录
+fn resolve(path: &str) -> Result<String, AppError> {
+    Ok(paths::resolve_user_path("path", path, None)?
+        .display()
+        .to_string())
+}
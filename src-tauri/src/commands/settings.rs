@@ -0,0 +1,30 @@
+//! Settings commands
+//! 设置命令
+
+use serde_json::Value;
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::models::settings::AppSettings;
+use crate::services::settings::SettingsStore;
+
+/// Returns the current settings
+/// 返回当前设置
+#[tauri::command]
+pub fn get_settings(settings: State<'_, SettingsStore>) -> AppSettings {
+    settings.get()
+}
+
+/// Updates some settings and returns the full, persisted result
+/// 更新部分设置并返回完整的已持久化结果
+///
+/// # Arguments
+/// * `partial` - Object with only the fields to change, e.g. `{ "compression_level": 7 }`
+///   仅包含需要修改字段的对象，例如 `{ "compression_level": 7 }`
+#[tauri::command]
+pub fn update_settings(
+    partial: Value,
+    settings: State<'_, SettingsStore>,
+) -> Result<AppSettings, AppError> {
+    settings.update(partial)
+}
@@ -0,0 +1,30 @@
+//! Staging directory commands
+//! 暂存目录命令
+
+use tauri::State;
+
+use crate::models::error::AppError;
+use crate::models::staging::StagingInfo;
+use crate::services::settings::SettingsStore;
+use crate::utils::temp_dir;
+
+/// Validates the staging directory and checks it has room for an operation
+/// 验证暂存目录并检查其是否有足够空间执行操作
+///
+/// # Arguments
+/// * `estimated_size` - Expected bytes the operation will stage, if known
+///   操作预计暂存的字节数（如已知）
+#[tauri::command]
+pub fn get_staging_info(
+    estimated_size: Option<u64>,
+    settings: State<'_, SettingsStore>,
+) -> Result<StagingInfo, AppError> {
+    let dir = temp_dir::operation_temp_dir(&settings.get())?;
+    let available_bytes = temp_dir::available_space(&dir)?;
+
+    Ok(StagingInfo {
+        path: dir.display().to_string(),
+        available_bytes,
+        low_space: estimated_size.is_some_and(|size| size > available_bytes),
+    })
+}
@@ -0,0 +1,43 @@
+//! Update check commands
+//! 更新检查命令
+
+use tauri::{AppHandle, Manager, State};
+
+use crate::models::error::AppError;
+use crate::models::updates::UpdateInfo;
+use crate::services::settings::SettingsStore;
+use crate::services::updates::{self, UpdateCache};
+
+/// Checks GitHub for a newer release
+/// 在 GitHub 上检查是否有更新的发布版本
+///
+/// # Arguments
+/// * `include_prereleases` - Also consider pre-release tags, defaults to false
+///   同时考虑预发布版本，默认为 false
+/// * `automatic` - The check was triggered automatically rather than by the user
+///   此次检查是自动触发而非用户手动触发
+///
+/// # Returns
+/// `None` when this is an automatic check and automatic checks are disabled
+/// 自动检查且已禁用自动检查时返回 `None`
+#[tauri::command]
+pub async fn check_for_updates(
+    app_handle: AppHandle,
+    include_prereleases: Option<bool>,
+    automatic: Option<bool>,
+    settings: State<'_, SettingsStore>,
+) -> Result<Option<UpdateInfo>, AppError> {
+    if automatic.unwrap_or(false) && !settings.get().auto_check_updates {
+        return Ok(None);
+    }
+
+    let include_prereleases = include_prereleases.unwrap_or(false);
+    tauri::async_runtime::spawn_blocking(move || {
+        updates::check(&app_handle.state::<UpdateCache>(), include_prereleases)
+    })
+    .await
+    .map_err(|e| AppError::Network {
+        message: e.to_string(),
+    })?
+    .map(Some)
+}
@@ -0,0 +1,46 @@
+//! Multi-volume split commands
+//! 分卷拆分命令
+
+use std::io;
+
+use crate::models::error::AppError;
+use crate::models::volumes::{VolumeEstimate, VolumeSizePreset};
+use crate::services::volumes;
+use crate::utils::paths;
+
+/// Returns the named volume sizes ("fits in an email", "FAT32 USB", CDs) plus "custom"
+/// 返回命名的分卷大小（“适合邮件附件”、“FAT32 U 盘”、光盘）以及 "custom"
+#[tauri::command]
+pub fn get_volume_size_presets() -> Vec<VolumeSizePreset> {
+    volumes::presets()
+}
+
+/// Previews how many volumes splitting `paths` with a preset will produce
+/// 预览使用某个预设拆分 `paths` 将产生多少个分卷
+///
+/// # Arguments
+/// * `paths` - Files and folders that will be archived
+///   将被压缩的文件和文件夹
+/// * `preset` - Preset name from `get_volume_size_presets`
+///   来自 `get_volume_size_presets` 的预设名称
+/// * `level` - Compression level 0-9
+///   压缩级别 0-9
+/// * `custom_bytes` - Volume size when `preset` is "custom"
+///   `preset` 为 "custom" 时的分卷大小
+#[tauri::command]
+pub async fn estimate_volume_count(
+    paths: Vec<String>,
+    preset: String,
+    level: u8,
+    custom_bytes: Option<u64>,
+) -> Result<VolumeEstimate, AppError> {
+    let volume_bytes = volumes::volume_bytes(&preset, custom_bytes)?;
+    let paths = paths
+        .iter()
+        .map(|path| paths::resolve_user_path("paths", path, None))
+        .collect::<Result<Vec<_>, _>>()?;
+    let first_path = paths.first().cloned().unwrap_or_default();
+    tauri::async_runtime::spawn_blocking(move || volumes::estimate(&paths, volume_bytes, level))
+        .await
+        .map_err(|e| AppError::io(&first_path, io::Error::other(e)))?
+}
@@ -0,0 +1,42 @@
+//! Window commands
+//! 窗口命令
+
+use tauri::{AppHandle, State, WebviewWindow};
+
+use crate::models::error::AppError;
+use crate::services::window_manager::{self, WindowArchives};
+use crate::utils::paths;
+
+/// Opens a new window, optionally loading an archive in it
+/// 打开新窗口，可选地在其中加载压缩包
+///
+/// # Arguments
+/// * `archive_path` - Archive to open in the new window
+///   要在新窗口中打开的压缩包
+///
+/// # Returns
+/// Label of the new window
+/// 新窗口的标签
+// Async so window creation doesn't deadlock the main thread on Windows
+#[tauri::command]
+pub async fn open_in_new_window(
+    app_handle: AppHandle,
+    archive_path: Option<String>,
+    windows: State<'_, WindowArchives>,
+) -> Result<String, AppError> {
+    let archive_path = archive_path
+        .map(|path| paths::resolve_user_path("archive_path", &path, None))
+        .transpose()?
+        .map(|path| path.display().to_string());
+    window_manager::open_window(&app_handle, &windows, archive_path)
+}
+
+/// Returns the archive the calling window was opened for, once
+/// 返回调用窗口被打开时指定的压缩包（仅返回一次）
+#[tauri::command]
+pub fn take_window_archive_path(
+    window: WebviewWindow,
+    windows: State<'_, WindowArchives>,
+) -> Option<String> {
+    windows.take(window.label())
+}
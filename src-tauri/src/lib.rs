@@ -1,3 +1,13 @@
+mod commands;
+mod models;
+mod services;
+mod utils;
+
+use tauri::{DragDropEvent, Manager, RunEvent, WindowEvent};
+
+use crate::utils::logger::log_info;
+use crate::utils::storage::storage_paths;
+
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -7,8 +17,133 @@ fn greet(name: &str) -> String {
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // Must be registered first so a second launch exits before doing any work
+        .plugin(tauri_plugin_single_instance::init(|app, args, cwd| {
+            services::open_requests::handle_second_instance(app, &args, &cwd);
+        }))
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .plugin(tauri_plugin_notification::init())
+        .manage(services::file_manager::RevealDebouncer::default())
+        .manage(services::window_manager::WindowArchives::default())
+        .manage(services::open_requests::OpenRequestQueue::default())
+        .manage(services::updates::UpdateCache::default())
+        .manage(services::taskbar::TaskbarProgress::default())
+        .manage(services::operations::ActiveOperations::default())
+        .setup(|app| {
+            utils::storage::init(app.handle())?;
+            let storage = storage_paths();
+            if let Err(e) = utils::logger::init(&storage.log_dir) {
+                eprintln!("Failed to open log file: {e}");
+            }
+            log_info!(
+                "SoarZip {} starting{}",
+                app.package_info().version,
+                if storage.portable {
+                    " in portable mode"
+                } else {
+                    ""
+                }
+            );
+
+            app.manage(services::settings::SettingsStore::load(
+                storage
+                    .config_dir
+                    .join(services::settings::SETTINGS_FILE_NAME),
+            ));
+            let settings = app.state::<services::settings::SettingsStore>();
+            utils::logger::set_level(settings.get().log_level);
+            let sweep_settings = settings.get();
+            std::thread::spawn(move || utils::temp_dir::sweep_stale_op_dirs(&sweep_settings));
+            services::file_associations::repoint(&settings.get().registered_associations);
+            if let Some(window) = app.get_webview_window("main") {
+                services::window_state::restore(&window, &settings);
+            }
+
+            app.manage(services::recent::RecentArchives::load(
+                storage.data_dir.join(services::recent::RECENT_FILE_NAME),
+            ));
+            app.manage(services::history::OperationHistory::new(
+                storage.data_dir.join(services::history::HISTORY_FILE_NAME),
+            ));
+
+            let args: Vec<String> = std::env::args().collect();
+            let cwd = std::env::current_dir().unwrap_or_default();
+            let paths = services::open_requests::archive_paths_from_args(&args, &cwd);
+            app.state::<services::open_requests::OpenRequestQueue>()
+                .enqueue(app.handle(), paths);
+            Ok(())
+        })
+        .on_window_event(|window, event| match event {
+            WindowEvent::CloseRequested { .. } if window.label() == "main" => {
+                services::window_state::save(window, &window.state());
+            }
+            WindowEvent::DragDrop(DragDropEvent::Drop { paths, .. }) => {
+                services::dropped_files::emit_dropped(window, paths);
+            }
+            WindowEvent::Destroyed => {
+                let windows = window.state::<services::window_manager::WindowArchives>();
+                windows.take(window.label());
+            }
+            _ => {}
+        })
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            commands::file_manager::show_in_folder,
+            commands::formats::get_supported_extensions,
+            commands::formats::quick_check_archive,
+            commands::checksum::verify_sidecar_checksum,
+            commands::volumes::get_volume_size_presets,
+            commands::volumes::estimate_volume_count,
+            commands::history::get_operation_history,
+            commands::history::clear_operation_history,
+            commands::clipboard::copy_paths_to_clipboard,
+            commands::clipboard::get_clipboard_files,
+            commands::diagnostics::get_runtime_diagnostics,
+            commands::recent::add_recent_archive,
+            commands::recent::get_recent_archives,
+            commands::recent::remove_recent_archive,
+            commands::recent::pin_recent_archive,
+            commands::recent::clear_recent_archives,
+            commands::settings::get_settings,
+            commands::settings::update_settings,
+            commands::profiles::list_profiles,
+            commands::profiles::save_profile,
+            commands::profiles::delete_profile,
+            commands::destination::get_default_extract_destination,
+            commands::destination::resolve_output_directory,
+            commands::destination::prepare_output_directory,
+            commands::destination::create_extract_subfolder,
+            commands::staging::get_staging_info,
+            commands::window::open_in_new_window,
+            commands::window::take_window_archive_path,
+            commands::open_requests::frontend_ready,
+            commands::open_requests::next_open_request,
+            commands::file_associations::register_file_associations,
+            commands::file_associations::unregister_file_associations,
+            commands::updates::check_for_updates,
+            commands::app_info::get_app_info,
+            commands::app_info::get_error_codes,
+            commands::logging::get_log_file_path,
+            commands::logging::open_log_folder,
+            commands::logging::get_recent_log_entries,
+            commands::logging::get_log_level,
+            commands::logging::set_log_level,
+        ])
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|app, event| match event {
+            RunEvent::ExitRequested { .. } => services::window_state::save_main(app),
+            // macOS delivers "Open with" files as an event instead of argv
+            #[cfg(any(target_os = "macos", target_os = "ios"))]
+            RunEvent::Opened { urls } => {
+                let paths = urls
+                    .into_iter()
+                    .filter_map(|url| url.to_file_path().ok())
+                    .filter(|path| services::open_requests::is_openable_archive(path))
+                    .collect();
+                app.state::<services::open_requests::OpenRequestQueue>()
+                    .enqueue(app, paths);
+            }
+            _ => {}
+        });
 }
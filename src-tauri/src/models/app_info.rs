@@ -0,0 +1,45 @@
+//! Application info models
+//! 应用程序信息模型
+
+use serde::Serialize;
+
+/// Version and environment details shown in the About dialog and bug reports
+/// 在“关于”对话框和错误报告中显示的版本与环境信息
+#[derive(Debug, Clone, Serialize)]
+pub struct AppInfo {
+    /// SoarZip version
+    /// SoarZip 版本
+    pub version: String,
+
+    /// Operating system, e.g. "windows"
+    /// 操作系统，例如 "windows"
+    pub os: String,
+
+    /// CPU architecture, e.g. "x86_64"
+    /// CPU 架构，例如 "x86_64"
+    pub arch: String,
+
+    /// Version of the system webview runtime, when it can be determined
+    /// 系统 WebView 运行时的版本（如可确定）
+    pub webview_version: Option<String>,
+
+    /// Running in portable mode, with everything stored next to the executable
+    /// 以便携模式运行，所有内容都存放在可执行文件旁
+    pub portable: bool,
+
+    /// Directory holding settings.json
+    /// 存放 settings.json 的目录
+    pub config_dir: String,
+
+    /// Directory holding persisted data such as the recent list
+    /// 存放最近列表等持久化数据的目录
+    pub data_dir: String,
+
+    /// Directory holding log files
+    /// 存放日志文件的目录
+    pub log_dir: String,
+
+    /// Directory holding caches
+    /// 存放缓存的目录
+    pub cache_dir: String,
+}
@@ -0,0 +1,70 @@
+//! Checksum verification models
+//! 校验和验证模型
+
+use serde::Serialize;
+
+use crate::models::metrics::OperationMetrics;
+use crate::models::progress::ProgressSnapshot;
+
+/// Outcome of comparing an archive with its sidecar checksum
+/// 将压缩包与其附带校验和文件比较的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChecksumVerdict {
+    /// The computed digest equals the published one
+    /// 计算出的摘要与发布的摘要一致
+    Matched,
+    /// The computed digest differs from the published one
+    /// 计算出的摘要与发布的摘要不一致
+    Mismatched,
+    /// No sidecar file mentions the archive
+    /// 没有任何校验和文件提到该压缩包
+    NoSidecarFound,
+}
+
+/// Result of `verify_sidecar_checksum`
+/// `verify_sidecar_checksum` 的结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ChecksumResult {
+    /// Overall verdict
+    /// 总体结论
+    pub verdict: ChecksumVerdict,
+
+    /// Digest algorithm used: "sha256", "sha1" or "md5"
+    /// 使用的摘要算法："sha256"、"sha1" 或 "md5"
+    pub algorithm: Option<String>,
+
+    /// Sidecar file the expected digest was read from
+    /// 读取预期摘要的校验和文件
+    pub sidecar_path: Option<String>,
+
+    /// Published digest, lowercase hex
+    /// 发布的摘要，小写十六进制
+    pub expected: Option<String>,
+
+    /// Computed digest, lowercase hex
+    /// 计算出的摘要，小写十六进制
+    pub actual: Option<String>,
+
+    /// Time taken and bytes hashed
+    /// 耗时与已计算摘要的字节数
+    pub metrics: Option<OperationMetrics>,
+}
+
+/// Payload of the `checksum-progress` event
+/// `checksum-progress` 事件的负载
+#[derive(Debug, Clone, Serialize)]
+pub struct ChecksumProgress {
+    /// Operation the event belongs to
+    /// 事件所属的操作
+    pub operation_id: String,
+
+    /// Archive being hashed
+    /// 正在计算摘要的压缩包
+    pub archive_path: String,
+
+    /// Bytes hashed out of the archive size, with speed and ETA
+    /// 已计算摘要的字节数与压缩包大小，以及速度和预计剩余时间
+    #[serde(flatten)]
+    pub progress: ProgressSnapshot,
+}
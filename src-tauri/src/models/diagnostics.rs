@@ -0,0 +1,59 @@
+//! Runtime diagnostics models
+//! 运行时诊断模型
+
+use serde::Serialize;
+
+/// An operation that is running right now
+/// 当前正在运行的操作
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveOperation {
+    /// Operation id, as used in progress events and log records
+    /// 操作 ID，与进度事件和日志记录中使用的一致
+    pub id: String,
+
+    /// Operation kind, as recorded in the history, e.g. "verify"
+    /// 操作类型，与历史记录中的一致，例如 "verify"
+    pub kind: String,
+
+    /// Seconds since the operation started
+    /// 自操作开始以来的秒数
+    pub age_secs: u64,
+}
+
+/// A per-operation staging folder currently on disk
+/// 当前存在于磁盘上的单个操作暂存文件夹
+#[derive(Debug, Clone, Serialize)]
+pub struct StagingDirUsage {
+    /// Folder path
+    /// 文件夹路径
+    pub path: String,
+
+    /// Total size of the files inside
+    /// 其中文件的总大小
+    pub bytes: u64,
+}
+
+/// Snapshot of the backend's managed state, for a diagnostics panel
+/// 后端托管状态的快照，用于诊断面板
+#[derive(Debug, Clone, Serialize)]
+pub struct RuntimeDiagnostics {
+    /// Operations currently running
+    /// 当前正在运行的操作
+    pub active_operations: Vec<ActiveOperation>,
+
+    /// `op-*` staging folders under the staging root, including leftovers
+    /// 暂存根目录下的 `op-*` 暂存文件夹，包括遗留的文件夹
+    pub staging_dirs: Vec<StagingDirUsage>,
+
+    /// Open webview windows
+    /// 已打开的 webview 窗口数
+    pub window_count: usize,
+
+    /// New windows whose frontend has not yet taken its archive path
+    /// 前端尚未取走压缩包路径的新窗口数
+    pub pending_window_archives: usize,
+
+    /// Archives waiting to be delivered to the main window
+    /// 等待投递给主窗口的压缩包数
+    pub queued_open_requests: usize,
+}
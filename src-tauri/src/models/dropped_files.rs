@@ -0,0 +1,30 @@
+//! Dropped file models
+//! 拖放文件模型
+
+use serde::Serialize;
+
+/// One path dropped onto a window, classified by the backend
+/// 拖放到窗口上的单个路径，由后端完成分类
+#[derive(Debug, Clone, Serialize)]
+pub struct DroppedItem {
+    /// Absolute path of the dropped item
+    /// 拖放项的绝对路径
+    pub path: String,
+
+    /// The item is a folder
+    /// 该项是文件夹
+    pub is_dir: bool,
+
+    /// Archive format detected from the file's magic bytes or extension, `None` for regular files
+    /// 根据文件魔数或扩展名检测到的压缩格式，普通文件为 `None`
+    pub archive_format: Option<String>,
+}
+
+/// Payload of the `files-dropped` event
+/// `files-dropped` 事件的负载
+#[derive(Debug, Clone, Serialize)]
+pub struct DroppedFiles {
+    /// Dropped items in drop order
+    /// 按拖放顺序排列的拖放项
+    pub items: Vec<DroppedItem>,
+}
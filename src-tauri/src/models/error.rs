@@ -0,0 +1,304 @@
+//! Application error type
+//! 应用程序错误类型
+//!
+//! Every command returns `AppError` on failure so the frontend receives a
+//! tagged object it can match on instead of a free-form message. It is
+//! serialized as `{ kind, code, params, detail }`: `code` is a stable key the
+//! frontend translates, `params` holds the values to interpolate and `detail`
+//! is the English message, kept only as a fallback.
+//! 所有命令在失败时返回 `AppError`，前端会收到可匹配的带标签对象而不是自由文本。
+//! 序列化形式为 `{ kind, code, params, detail }`：`code` 是前端用于翻译的稳定键，
+//! `params` 包含需要插入的值，`detail` 是英文消息，仅作为后备。
+
+use std::io::ErrorKind;
+
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::{Value, json};
+
+use crate::utils::retry::FileLocked;
+
+/// Every error code the backend can emit, for the frontend's translation table
+/// 后端可能返回的所有错误代码，供前端的翻译表使用
+pub const ERROR_CODES: &[&str] = &[
+    "path.notFound",
+    "process.launchFailed",
+    "io.failed",
+    "path.permissionDenied",
+    "file.locked",
+    "staging.unavailable",
+    "window.creationFailed",
+    "platform.unsupported",
+    "argument.invalid",
+    "network.failed",
+    "settings.invalid",
+    "clipboard.failed",
+];
+
+/// Errors surfaced to the frontend by backend commands
+/// 后端命令向前端返回的错误
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    /// The given path does not exist
+    /// 指定的路径不存在
+    #[error("Path not found: {path}")]
+    NotFound { path: String },
+
+    /// An external program could not be started or reported failure
+    /// 外部程序无法启动或执行失败
+    #[error("Failed to run {program}: {message}")]
+    ProcessLaunch { program: String, message: String },
+
+    /// A file system operation failed
+    /// 文件系统操作失败
+    #[error("I/O error on {path}: {message}")]
+    Io { path: String, message: String },
+
+    /// The path cannot be written, for lack of permission or because its volume is read-only
+    /// 无法写入该路径，原因是没有权限或所在卷为只读
+    #[error("Permission denied for {path}: {message}")]
+    PermissionDenied { path: String, message: String },
+
+    /// A file stayed locked by another process for the whole retry budget
+    /// 文件在整个重试时间内始终被其他进程锁定
+    #[error("{path} is locked by another process: {message}")]
+    FileLocked {
+        path: String,
+        message: String,
+        retried_ms: u64,
+    },
+
+    /// The staging directory is missing or not writable
+    /// 暂存目录不存在或不可写
+    #[error("Temp directory {path} is unavailable: {message}")]
+    TempDirUnavailable { path: String, message: String },
+
+    /// A new window could not be created
+    /// 无法创建新窗口
+    #[error("Failed to create window: {message}")]
+    WindowCreation { message: String },
+
+    /// The feature is not available on this operating system
+    /// 此功能在当前操作系统上不可用
+    #[error("{feature} is not supported on this platform")]
+    UnsupportedPlatform { feature: String },
+
+    /// A command argument is invalid
+    /// 命令参数无效
+    #[error("Invalid argument {name}: {message}")]
+    InvalidArgument { name: String, message: String },
+
+    /// A network request failed or timed out
+    /// 网络请求失败或超时
+    #[error("Network request failed: {message}")]
+    Network { message: String },
+
+    /// A settings update was rejected
+    /// 设置更新被拒绝
+    #[error("Invalid setting {key}: {message}")]
+    InvalidSetting { key: String, message: String },
+
+    /// The system clipboard could not be accessed
+    /// 无法访问系统剪贴板
+    #[error("Clipboard error: {message}")]
+    Clipboard { message: String },
+}
+
+impl AppError {
+    /// Stable translation key of the error, one of [`ERROR_CODES`]
+    /// 错误的稳定翻译键，取值见 [`ERROR_CODES`]
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound { .. } => "path.notFound",
+            AppError::ProcessLaunch { .. } => "process.launchFailed",
+            AppError::Io { .. } => "io.failed",
+            AppError::PermissionDenied { .. } => "path.permissionDenied",
+            AppError::FileLocked { .. } => "file.locked",
+            AppError::TempDirUnavailable { .. } => "staging.unavailable",
+            AppError::WindowCreation { .. } => "window.creationFailed",
+            AppError::UnsupportedPlatform { .. } => "platform.unsupported",
+            AppError::InvalidArgument { .. } => "argument.invalid",
+            AppError::Network { .. } => "network.failed",
+            AppError::InvalidSetting { .. } => "settings.invalid",
+            AppError::Clipboard { .. } => "clipboard.failed",
+        }
+    }
+
+    /// Variant name and the values a translation interpolates
+    /// 变体名称以及翻译时需要插入的值
+    fn kind_and_params(&self) -> (&'static str, Value) {
+        match self {
+            AppError::NotFound { path } => ("notFound", json!({ "path": path })),
+            AppError::ProcessLaunch { program, message } => (
+                "processLaunch",
+                json!({ "program": program, "message": message }),
+            ),
+            AppError::Io { path, message } => ("io", json!({ "path": path, "message": message })),
+            AppError::PermissionDenied { path, message } => (
+                "permissionDenied",
+                json!({ "path": path, "message": message }),
+            ),
+            AppError::FileLocked {
+                path,
+                message,
+                retried_ms,
+            } => (
+                "fileLocked",
+                json!({ "path": path, "message": message, "retriedMs": retried_ms }),
+            ),
+            AppError::TempDirUnavailable { path, message } => (
+                "tempDirUnavailable",
+                json!({ "path": path, "message": message }),
+            ),
+            AppError::WindowCreation { message } => {
+                ("windowCreation", json!({ "message": message }))
+            }
+            AppError::UnsupportedPlatform { feature } => {
+                ("unsupportedPlatform", json!({ "feature": feature }))
+            }
+            AppError::InvalidArgument { name, message } => (
+                "invalidArgument",
+                json!({ "name": name, "message": message }),
+            ),
+            AppError::Network { message } => ("network", json!({ "message": message })),
+            AppError::InvalidSetting { key, message } => {
+                ("invalidSetting", json!({ "key": key, "message": message }))
+            }
+            AppError::Clipboard { message } => ("clipboard", json!({ "message": message })),
+        }
+    }
+
+    /// Builds an `Io` error from a `std::io::Error` and the path involved
+    /// 根据 `std::io::Error` 和相关路径构造 `Io` 错误
+    ///
+    /// Missing paths become `NotFound`, permission and read-only volume errors
+    /// become `PermissionDenied` and errors from the lock retry helper become
+    /// `FileLocked`, so a failure after a successful pre-flight check is still typed.
+    /// 路径不存在时转为 `NotFound`，权限和只读卷错误转为 `PermissionDenied`，
+    /// 来自锁定重试辅助函数的错误转为 `FileLocked`，因此预检通过后发生的失败仍然有明确类型。
+    pub fn io(path: impl AsRef<std::path::Path>, error: std::io::Error) -> Self {
+        let path_text = path.as_ref().display().to_string();
+        match error.kind() {
+            ErrorKind::NotFound => return AppError::NotFound { path: path_text },
+            ErrorKind::PermissionDenied | ErrorKind::ReadOnlyFilesystem => {
+                return AppError::PermissionDenied {
+                    path: path_text,
+                    message: error.to_string(),
+                };
+            }
+            _ => {}
+        }
+        if let Some(locked) = error
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<FileLocked>())
+        {
+            return AppError::FileLocked {
+                path: path_text,
+                message: locked.source.to_string(),
+                retried_ms: locked.retried.as_millis() as u64,
+            };
+        }
+        AppError::Io {
+            path: path_text,
+            message: error.to_string(),
+        }
+    }
+}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (kind, params) = self.kind_and_params();
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("kind", kind)?;
+        map.serialize_entry("code", self.code())?;
+        map.serialize_entry("params", &params)?;
+        map.serialize_entry("detail", &self.to_string())?;
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    /// One value of every variant; the match below stops compiling when a variant is added
+    fn every_variant() -> Vec<AppError> {
+        let text = || "x".to_string();
+        let errors = vec![
+            AppError::NotFound { path: text() },
+            AppError::ProcessLaunch {
+                program: text(),
+                message: text(),
+            },
+            AppError::Io {
+                path: text(),
+                message: text(),
+            },
+            AppError::PermissionDenied {
+                path: text(),
+                message: text(),
+            },
+            AppError::FileLocked {
+                path: text(),
+                message: text(),
+                retried_ms: 0,
+            },
+            AppError::TempDirUnavailable {
+                path: text(),
+                message: text(),
+            },
+            AppError::WindowCreation { message: text() },
+            AppError::UnsupportedPlatform { feature: text() },
+            AppError::InvalidArgument {
+                name: text(),
+                message: text(),
+            },
+            AppError::Network { message: text() },
+            AppError::InvalidSetting {
+                key: text(),
+                message: text(),
+            },
+            AppError::Clipboard { message: text() },
+        ];
+        for error in &errors {
+            match error {
+                AppError::NotFound { .. }
+                | AppError::ProcessLaunch { .. }
+                | AppError::Io { .. }
+                | AppError::PermissionDenied { .. }
+                | AppError::FileLocked { .. }
+                | AppError::TempDirUnavailable { .. }
+                | AppError::WindowCreation { .. }
+                | AppError::UnsupportedPlatform { .. }
+                | AppError::InvalidArgument { .. }
+                | AppError::Network { .. }
+                | AppError::InvalidSetting { .. }
+                | AppError::Clipboard { .. } => {}
+            }
+        }
+        errors
+    }
+
+    #[test]
+    fn error_codes_list_every_variant_code_once() {
+        let listed: HashSet<&str> = ERROR_CODES.iter().copied().collect();
+        assert_eq!(listed.len(), ERROR_CODES.len(), "duplicate in ERROR_CODES");
+
+        let errors = every_variant();
+        let emitted: HashSet<&str> = errors.iter().map(AppError::code).collect();
+        assert_eq!(emitted.len(), errors.len(), "two variants share a code");
+        assert_eq!(emitted, listed);
+    }
+
+    #[test]
+    fn serializes_code_and_kind() {
+        let value = serde_json::to_value(AppError::NotFound {
+            path: "a.zip".to_string(),
+        })
+        .unwrap();
+        assert_eq!(value["kind"], "notFound");
+        assert_eq!(value["code"], "path.notFound");
+        assert_eq!(value["params"]["path"], "a.zip");
+    }
+}
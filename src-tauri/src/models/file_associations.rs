@@ -0,0 +1,21 @@
+//! File association models
+//! 文件关联模型
+
+use serde::Serialize;
+
+/// Outcome of registering or unregistering one extension
+/// 注册或取消注册单个扩展名的结果
+#[derive(Debug, Clone, Serialize)]
+pub struct AssociationResult {
+    /// Extension without the leading dot, e.g. "zip"
+    /// 不带前导点的扩展名，例如 "zip"
+    pub extension: String,
+
+    /// Whether the registry update succeeded
+    /// 注册表更新是否成功
+    pub success: bool,
+
+    /// Failure reason when `success` is false
+    /// `success` 为 false 时的失败原因
+    pub error: Option<String>,
+}
@@ -0,0 +1,41 @@
+//! Archive format models
+//! 压缩格式模型
+
+use serde::Serialize;
+
+/// One extension the app can open
+/// 应用可以打开的一个扩展名
+#[derive(Debug, Clone, Serialize)]
+pub struct SupportedExtension {
+    /// Lowercase extension without the dot, e.g. "tar.gz"
+    /// 不含点的小写扩展名，例如 "tar.gz"
+    pub extension: String,
+
+    /// Format the extension implies, e.g. "gzip"
+    /// 该扩展名对应的格式，例如 "gzip"
+    pub format: String,
+
+    /// 7-Zip can create new archives of this format
+    /// 7-Zip 可以创建此格式的新压缩包
+    pub creatable: bool,
+
+    /// 7-Zip can add to and delete from existing archives of this format; otherwise browsing is read-only
+    /// 7-Zip 可以向此格式的已有压缩包添加或删除内容；否则只能只读浏览
+    pub writable: bool,
+}
+
+/// Verdict of the quick structural check run before listing an archive
+/// 列出压缩包内容前快速结构检查的结论
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveHealth {
+    /// The header and trailer are consistent with the file size
+    /// 头部和尾部与文件大小一致
+    Healthy,
+    /// The file ends before the structures it declares, e.g. an interrupted download
+    /// 文件在其声明的结构之前就已结束，例如下载中断
+    Truncated,
+    /// The format is not checked structurally
+    /// 该格式不进行结构检查
+    Unknown,
+}
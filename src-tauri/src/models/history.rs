@@ -0,0 +1,80 @@
+//! Operation history models
+//! 操作历史模型
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::metrics::OperationMetrics;
+
+/// How an operation ended
+/// 操作的结束方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationOutcome {
+    /// Completed as requested
+    /// 按要求完成
+    Success,
+    /// Completed, but with something the user should look at
+    /// 已完成，但有需要用户注意的情况
+    Warning,
+    /// Did not complete
+    /// 未完成
+    Failure,
+}
+
+/// One finished operation; never holds passwords or file lists
+/// 一次已完成的操作；从不保存密码或文件列表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Completion time, seconds since the Unix epoch
+    /// 完成时间，自 Unix 纪元起的秒数
+    pub timestamp: u64,
+
+    /// Operation kind, e.g. "extract", "create", "modify", "verify"
+    /// 操作类型，例如 "extract"、"create"、"modify"、"verify"
+    pub kind: String,
+
+    /// Archive the operation worked on
+    /// 操作所针对的压缩包
+    pub archive_path: String,
+
+    /// Output folder or file, when the operation has one
+    /// 输出文件夹或文件（如有）
+    #[serde(default)]
+    pub destination: Option<String>,
+
+    /// How the operation ended
+    /// 操作的结束方式
+    pub outcome: OperationOutcome,
+
+    /// Short explanation for warnings and failures
+    /// 警告和失败的简短说明
+    #[serde(default)]
+    pub message: Option<String>,
+
+    /// Duration and amount of work done
+    /// 耗时与完成的工作量
+    #[serde(flatten)]
+    pub metrics: OperationMetrics,
+}
+
+/// Criteria for `get_operation_history`; unset fields match everything
+/// `get_operation_history` 的筛选条件；未设置的字段匹配所有条目
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HistoryFilter {
+    /// Only entries of this kind
+    /// 仅此类型的条目
+    pub kind: Option<String>,
+
+    /// Only entries for this archive
+    /// 仅此压缩包的条目
+    pub archive_path: Option<String>,
+
+    /// Only entries completed at or after this Unix time
+    /// 仅在此 Unix 时间或之后完成的条目
+    pub since: Option<u64>,
+
+    /// Only entries with this outcome
+    /// 仅此结束方式的条目
+    pub outcome: Option<OperationOutcome>,
+}
@@ -0,0 +1,32 @@
+//! Log record models
+//! 日志记录模型
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::logger::Level;
+
+/// One line of the JSON-lines log file
+/// JSON-lines 日志文件中的一行
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// Local time the record was written, `YYYY-MM-DD HH:MM:SS.mmm`
+    /// 记录写入时的本地时间，格式为 `YYYY-MM-DD HH:MM:SS.mmm`
+    pub timestamp: String,
+
+    /// Severity of the record
+    /// 记录的严重级别
+    pub level: Level,
+
+    /// Rust module that wrote the record
+    /// 写入记录的 Rust 模块
+    pub module: String,
+
+    /// Operation the record belongs to, if any
+    /// 记录所属的操作（如有）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+
+    /// Formatted message
+    /// 格式化后的消息
+    pub message: String,
+}
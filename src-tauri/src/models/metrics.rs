@@ -0,0 +1,28 @@
+//! Operation metrics model
+//! 操作指标模型
+
+use serde::{Deserialize, Serialize};
+
+/// Timing and throughput of a finished operation, shared by command results and history
+/// 已完成操作的耗时与吞吐量，由命令结果和操作历史共用
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OperationMetrics {
+    /// Start time, seconds since the Unix epoch
+    /// 开始时间，自 Unix 纪元起的秒数
+    #[serde(default)]
+    pub started_at: u64,
+
+    /// Wall-clock duration in milliseconds
+    /// 实际耗时（毫秒）
+    pub duration_ms: u64,
+
+    /// Bytes processed, when known
+    /// 已处理的字节数（如已知）
+    #[serde(default)]
+    pub bytes_processed: Option<u64>,
+
+    /// Archive entries processed, when known
+    /// 已处理的压缩包条目数（如已知）
+    #[serde(default)]
+    pub entries_processed: Option<u64>,
+}
@@ -0,0 +1,20 @@
+//! Data models shared between commands and services
+//! 命令与服务之间共享的数据模型
+
+pub mod app_info;
+pub mod checksum;
+pub mod diagnostics;
+pub mod dropped_files;
+pub mod error;
+pub mod file_associations;
+pub mod formats;
+pub mod history;
+pub mod log;
+pub mod metrics;
+pub mod profiles;
+pub mod progress;
+pub mod recent;
+pub mod settings;
+pub mod staging;
+pub mod updates;
+pub mod volumes;
@@ -0,0 +1,59 @@
+//! Compression profile models
+//! 压缩配置方案模型
+
+use serde::{Deserialize, Serialize};
+
+/// A named combination of format and compression options
+/// 格式与压缩选项的命名组合
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompressionProfile {
+    /// Archive format, one of the writable formats
+    /// 压缩格式，必须为可写格式之一
+    pub archive_type: String,
+
+    /// Compression level, 0 (store) to 9 (ultra)
+    /// 压缩级别，0（仅存储）到 9（极限）
+    pub compression_level: u8,
+
+    /// Wildcard patterns of files to leave out, e.g. "node_modules"
+    /// 要排除的文件通配符模式，例如 "node_modules"
+    pub exclude_patterns: Vec<String>,
+
+    /// Ask for a password and encrypt the archive
+    /// 询问密码并加密压缩包
+    pub encrypt: bool,
+
+    /// Split into volumes of this many bytes, `None` for a single file
+    /// 按此字节数拆分为分卷，`None` 表示单个文件
+    pub volume_size: Option<u64>,
+}
+
+impl Default for CompressionProfile {
+    fn default() -> Self {
+        Self {
+            archive_type: "7z".to_string(),
+            compression_level: 5,
+            exclude_patterns: Vec::new(),
+            encrypt: false,
+            volume_size: None,
+        }
+    }
+}
+
+/// A profile as listed to the frontend
+/// 列给前端的配置方案
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileEntry {
+    /// Profile name, unique across built-in and user profiles
+    /// 配置方案名称，在内置和用户配置方案之间唯一
+    pub name: String,
+
+    /// Shipped with the app; cannot be changed or deleted
+    /// 随应用提供；不能修改或删除
+    pub built_in: bool,
+
+    /// The profile's settings
+    /// 配置方案的设置
+    pub profile: CompressionProfile,
+}
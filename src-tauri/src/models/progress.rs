@@ -0,0 +1,29 @@
+//! Progress reporting models
+//! 进度报告模型
+
+use serde::Serialize;
+
+/// Byte counters, throughput and ETA shared by all progress events
+/// 所有进度事件共用的字节计数、吞吐量和预计剩余时间
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgressSnapshot {
+    /// Bytes processed so far
+    /// 已处理的字节数
+    pub processed_bytes: u64,
+
+    /// Total bytes expected, 0 when unknown
+    /// 预计的总字节数，未知时为 0
+    pub total_bytes: u64,
+
+    /// Completion in percent, `None` when the total is unknown
+    /// 完成百分比，总量未知时为 `None`
+    pub percent: Option<f64>,
+
+    /// Throughput smoothed over the last few seconds, `None` until there is enough data
+    /// 最近几秒内平滑后的吞吐量，数据不足时为 `None`
+    pub bytes_per_second: Option<u64>,
+
+    /// Estimated seconds remaining, `None` when throughput or total is unknown
+    /// 预计剩余秒数，吞吐量或总量未知时为 `None`
+    pub eta_seconds: Option<u64>,
+}
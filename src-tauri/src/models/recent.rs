@@ -0,0 +1,46 @@
+//! Recent archive models
+//! 最近打开的压缩包模型
+
+use serde::Serialize;
+
+/// A recently opened archive as shown in the recent list
+/// 最近列表中显示的一个最近打开的压缩包
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentArchive {
+    /// Absolute path of the archive
+    /// 压缩包的绝对路径
+    pub path: String,
+
+    /// File name component of `path`
+    /// `path` 中的文件名部分
+    pub file_name: String,
+
+    /// Current size in bytes, `None` when the file is missing
+    /// 当前大小（字节），文件不存在时为 `None`
+    pub size: Option<u64>,
+
+    /// Last time the archive was opened, in seconds since the Unix epoch
+    /// 上次打开该压缩包的时间（Unix 纪元以来的秒数）
+    pub last_opened: u64,
+
+    /// Pinned entries are never evicted by the list cap
+    /// 固定条目永远不会因列表上限被移除
+    pub pinned: bool,
+
+    /// The file no longer exists; unpinned entries have been pruned from the store
+    /// 文件已不存在；未固定的条目已从存储中移除
+    pub missing: bool,
+}
+
+/// Recent list split into pinned and regular sections
+/// 分为固定区和普通区的最近列表
+#[derive(Debug, Clone, Serialize)]
+pub struct RecentArchivesList {
+    /// Pinned archives, never evicted
+    /// 固定的压缩包，永不被移除
+    pub pinned: Vec<RecentArchive>,
+
+    /// Unpinned archives, most recent first
+    /// 未固定的压缩包，最近的在前
+    pub recent: Vec<RecentArchive>,
+}
@@ -0,0 +1,153 @@
+//! Application settings model
+//! 应用程序设置模型
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::models::profiles::CompressionProfile;
+use crate::utils::logger::Level;
+
+/// What to do when an extracted file already exists
+/// 解压的文件已存在时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum OverwritePolicy {
+    /// Ask the user for each conflict
+    /// 每次冲突时询问用户
+    #[default]
+    Ask,
+    /// Replace existing files
+    /// 替换已存在的文件
+    Overwrite,
+    /// Keep existing files
+    /// 保留已存在的文件
+    Skip,
+    /// Rename the newly extracted file
+    /// 重命名新解压的文件
+    RenameNew,
+    /// Rename the existing file
+    /// 重命名已存在的文件
+    RenameExisting,
+}
+
+/// Where extraction goes when the user doesn't pick a folder
+/// 用户未选择文件夹时解压的目标位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DestinationMode {
+    /// The folder containing the archive
+    /// 压缩包所在的文件夹
+    #[default]
+    ArchiveDirectory,
+    /// The configured `default_extract_dir`
+    /// 配置的 `default_extract_dir`
+    DefaultDirectory,
+    /// The folder used for the previous extraction
+    /// 上次解压使用的文件夹
+    LastUsed,
+}
+
+/// Saved size, position and maximized state of the main window, in physical pixels
+/// 主窗口保存的大小、位置和最大化状态（物理像素）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+}
+
+/// Persisted application settings
+/// 持久化的应用程序设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Default compression level, 0 (store) to 9 (ultra)
+    /// 默认压缩级别，0（仅存储）到 9（极限）
+    pub compression_level: u8,
+
+    /// Default format for new archives
+    /// 新建压缩包的默认格式
+    pub default_archive_type: String,
+
+    /// Default conflict handling when extracting
+    /// 解压时默认的冲突处理方式
+    pub overwrite_policy: OverwritePolicy,
+
+    /// How the default extraction destination is chosen
+    /// 默认解压目标的选择方式
+    pub destination_mode: DestinationMode,
+
+    /// Folder used when `destination_mode` is `DefaultDirectory`
+    /// `destination_mode` 为 `DefaultDirectory` 时使用的文件夹
+    pub default_extract_dir: Option<String>,
+
+    /// Folder of the most recent extraction, used by `LastUsed`
+    /// 最近一次解压的文件夹，供 `LastUsed` 使用
+    pub last_extract_dir: Option<String>,
+
+    /// Staging root for temp-heavy operations, `None` for `<system temp>/soarzip`
+    /// 大量使用临时文件的操作的暂存根目录，`None` 表示 `<系统临时目录>/soarzip`
+    pub temp_dir_override: Option<String>,
+
+    /// Main window geometry from the previous session
+    /// 上一次会话的主窗口几何信息
+    pub window_state: Option<WindowGeometry>,
+
+    /// Archives forwarded by a second launch open in a new window instead of the main one
+    /// 二次启动转发的压缩包在新窗口而不是主窗口中打开
+    pub open_forwarded_in_new_window: bool,
+
+    /// Extensions SoarZip registered itself for, re-pointed at startup if the exe moved
+    /// SoarZip 已注册的扩展名，若可执行文件移动则在启动时重新指向
+    pub registered_associations: Vec<String>,
+
+    /// Check GitHub for new releases automatically at startup
+    /// 启动时自动在 GitHub 上检查新版本
+    pub auto_check_updates: bool,
+
+    /// Minimum level written to the log; `debug` enables verbose logging
+    /// 写入日志的最低级别；`debug` 表示开启详细日志
+    pub log_level: Level,
+
+    /// Show a desktop notification when a long operation finishes in the background
+    /// 长时间运行的操作在后台完成时显示桌面通知
+    pub notify_on_completion: bool,
+
+    /// Minimum operation duration in seconds before a completion notification is shown
+    /// 显示完成通知所需的最短操作时长（秒）
+    pub notification_threshold_secs: u64,
+
+    /// User compression profiles by name; built-in profiles are not stored
+    /// 按名称保存的用户压缩配置方案；内置配置方案不会被保存
+    pub compression_profiles: BTreeMap<String, CompressionProfile>,
+
+    /// Fields written by other versions of the app, kept so rewrites don't drop them
+    /// 其他版本写入的字段，保留下来以免重写时丢失
+    #[serde(flatten)]
+    pub unknown: Map<String, Value>,
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            compression_level: 5,
+            default_archive_type: "7z".to_string(),
+            overwrite_policy: OverwritePolicy::default(),
+            destination_mode: DestinationMode::default(),
+            default_extract_dir: None,
+            last_extract_dir: None,
+            temp_dir_override: None,
+            window_state: None,
+            open_forwarded_in_new_window: false,
+            registered_associations: Vec::new(),
+            auto_check_updates: true,
+            log_level: Level::default(),
+            notify_on_completion: true,
+            notification_threshold_secs: 30,
+            compression_profiles: BTreeMap::new(),
+            unknown: Map::new(),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Staging directory models
+//! 暂存目录模型
+
+use serde::Serialize;
+
+/// Staging directory status reported before a temp-heavy operation
+/// 在大量使用临时文件的操作之前报告的暂存目录状态
+#[derive(Debug, Serialize)]
+pub struct StagingInfo {
+    /// Staging root that operations will use
+    /// 操作将使用的暂存根目录
+    pub path: String,
+
+    /// Free bytes on the staging volume
+    /// 暂存卷上的空闲字节数
+    pub available_bytes: u64,
+
+    /// The volume has less free space than the estimated operation size
+    /// 该卷的空闲空间小于预估的操作大小
+    pub low_space: bool,
+}
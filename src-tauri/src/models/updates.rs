@@ -0,0 +1,29 @@
+//! Update check models
+//! 更新检查模型
+
+use serde::Serialize;
+
+/// Result of comparing the running version with the latest GitHub release
+/// 将当前运行版本与 GitHub 最新发布版本比较的结果
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateInfo {
+    /// Version of the running app
+    /// 当前运行应用的版本
+    pub current_version: String,
+
+    /// Newest release version found
+    /// 找到的最新发布版本
+    pub latest_version: String,
+
+    /// `latest_version` is newer than `current_version`
+    /// `latest_version` 比 `current_version` 更新
+    pub update_available: bool,
+
+    /// Markdown body of the release
+    /// 发布说明的 Markdown 正文
+    pub release_notes: String,
+
+    /// Installer asset for this platform, or the release page when none matches
+    /// 适用于当前平台的安装包，没有匹配项时为发布页面
+    pub download_url: String,
+}
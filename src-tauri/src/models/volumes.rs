@@ -0,0 +1,42 @@
+//! Multi-volume split models
+//! 分卷拆分模型
+
+use serde::Serialize;
+
+/// A named volume size for splitting archives
+/// 用于拆分压缩包的命名分卷大小
+#[derive(Debug, Clone, Serialize)]
+pub struct VolumeSizePreset {
+    /// Preset identifier accepted wherever a preset name is expected, e.g. "fat32"
+    /// 预设标识符，可用于所有接受预设名称的地方，例如 "fat32"
+    pub name: String,
+
+    /// Exact volume size in bytes, `None` for "custom"
+    /// 精确的分卷大小（字节），"custom" 为 `None`
+    pub bytes: Option<u64>,
+}
+
+/// Preview of how a set of sources will be split
+/// 一组源文件将如何被拆分的预览
+#[derive(Debug, Clone, Serialize)]
+pub struct VolumeEstimate {
+    /// Total size of the source files
+    /// 源文件的总大小
+    pub source_bytes: u64,
+
+    /// Largest archive size to expect: the sources stored uncompressed plus headers
+    /// 预计的最大压缩包大小：源文件以不压缩方式存储再加上头部
+    pub estimated_archive_bytes: u64,
+
+    /// Size of each volume
+    /// 每个分卷的大小
+    pub volume_bytes: u64,
+
+    /// Most volumes to expect, at least 1
+    /// 预计最多的分卷数量，至少为 1
+    pub volume_count: u64,
+
+    /// Volumes if the sources compress like typical documents, at least 1
+    /// 源文件像典型文档一样可压缩时的分卷数量，至少为 1
+    pub best_case_volume_count: u64,
+}
@@ -0,0 +1,391 @@
+//! Sidecar checksum verification service
+//! 附带校验和文件的验证服务
+//!
+//! Finds a published digest for an archive (`<archive>.sha256`, `.sha1`, `.md5`
+//! or a `SHA256SUMS`-style list next to it) and compares it with the archive's
+//! actual digest, hashing in a streaming fashion with progress events.
+//! 查找压缩包已发布的摘要（`<archive>.sha256`、`.sha1`、`.md5` 或同目录下
+//! `SHA256SUMS` 风格的列表），并以流式方式计算压缩包的实际摘要进行比较，同时发送进度事件。
+
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::models::checksum::{ChecksumProgress, ChecksumResult, ChecksumVerdict};
+use crate::models::error::AppError;
+use crate::services::taskbar::TaskbarProgress;
+use crate::utils::logger::log_info;
+use crate::utils::progress::ProgressTracker;
+
+/// Event reporting hashing progress
+/// 报告摘要计算进度的事件
+pub const CHECKSUM_PROGRESS_EVENT: &str = "checksum-progress";
+
+/// Minimum interval between two progress events
+/// 两次进度事件之间的最小间隔
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Read buffer size used while hashing
+/// 计算摘要时使用的读取缓冲区大小
+const HASH_BUFFER_SIZE: usize = 1024 * 1024;
+
+/// Digest algorithms a sidecar can name, strongest first
+/// 校验和文件可以使用的摘要算法，按强度从高到低排列
+#[derive(Debug, Clone, Copy)]
+enum Algorithm {
+    Sha256,
+    Sha1,
+    Md5,
+}
+
+impl Algorithm {
+    const ALL: [Algorithm; 3] = [Algorithm::Sha256, Algorithm::Sha1, Algorithm::Md5];
+
+    fn name(self) -> &'static str {
+        match self {
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha1 => "sha1",
+            Algorithm::Md5 => "md5",
+        }
+    }
+
+    fn hex_len(self) -> usize {
+        match self {
+            Algorithm::Sha256 => 64,
+            Algorithm::Sha1 => 40,
+            Algorithm::Md5 => 32,
+        }
+    }
+}
+
+/// A published digest and where it came from
+/// 已发布的摘要及其来源
+struct Sidecar {
+    path: PathBuf,
+    algorithm: Algorithm,
+    expected: String,
+}
+
+/// Compares `archive` with the first sidecar checksum found for it
+/// 将 `archive` 与找到的第一个校验和文件进行比较
+///
+/// # Arguments
+/// * `app` - Used to emit `checksum-progress` events
+///   用于发送 `checksum-progress` 事件
+/// * `archive` - Archive to verify
+///   要验证的压缩包
+/// * `operation_id` - Tags progress events, taskbar progress and log records
+///   用于标记进度事件、任务栏进度和日志记录
+pub fn verify_sidecar<R: Runtime>(
+    app: &AppHandle<R>,
+    archive: &Path,
+    operation_id: &str,
+) -> Result<ChecksumResult, AppError> {
+    if !archive.is_file() {
+        return Err(AppError::NotFound {
+            path: archive.display().to_string(),
+        });
+    }
+
+    let Some(sidecar) = find_sidecar(archive) else {
+        return Ok(ChecksumResult {
+            verdict: ChecksumVerdict::NoSidecarFound,
+            algorithm: None,
+            sidecar_path: None,
+            expected: None,
+            actual: None,
+            metrics: None,
+        });
+    };
+
+    let actual = hash_file(app, archive, sidecar.algorithm, operation_id)
+        .map_err(|e| AppError::io(archive, e))?;
+    let verdict = if actual == sidecar.expected {
+        ChecksumVerdict::Matched
+    } else {
+        ChecksumVerdict::Mismatched
+    };
+    log_info!(
+        op: operation_id;
+        "Checksum of {} against {}: {verdict:?}",
+        archive.display(),
+        sidecar.path.display()
+    );
+
+    Ok(ChecksumResult {
+        verdict,
+        algorithm: Some(sidecar.algorithm.name().to_string()),
+        sidecar_path: Some(sidecar.path.display().to_string()),
+        expected: Some(sidecar.expected),
+        actual: Some(actual),
+        metrics: None,
+    })
+}
+
+/// Looks for `<archive>.<algorithm>` first, then for a digest list in the same folder
+/// 先查找 `<archive>.<algorithm>`，再查找同一文件夹中的摘要列表
+fn find_sidecar(archive: &Path) -> Option<Sidecar> {
+    let name = archive.file_name()?.to_string_lossy().into_owned();
+    let dir = archive.parent()?;
+
+    for algorithm in Algorithm::ALL {
+        let path = dir.join(format!("{name}.{}", algorithm.name()));
+        if let Some(expected) = read_digest(&path, algorithm, &name, true) {
+            return Some(Sidecar {
+                path,
+                algorithm,
+                expected,
+            });
+        }
+    }
+
+    let entries: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .collect();
+    for algorithm in Algorithm::ALL {
+        let list_name = format!("{}sums", algorithm.name());
+        for path in &entries {
+            let file_name = path
+                .file_name()
+                .map(|file_name| file_name.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            if file_name != list_name && file_name != format!("{list_name}.txt") {
+                continue;
+            }
+            if let Some(expected) = read_digest(path, algorithm, &name, false) {
+                return Some(Sidecar {
+                    path: path.clone(),
+                    algorithm,
+                    expected,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Reads the digest listed for `name` from a checksum file
+/// 从校验和文件中读取 `name` 对应的摘要
+///
+/// Understands GNU (`<hex>  name`, `<hex> *name`) and BSD
+/// (`SHA256 (name) = <hex>`) lines. A digest list sits next to the archive,
+/// so its entry must be `name` itself, optionally as `./name`; `sub/name`
+/// is another file. A `single_file` sidecar is named after the archive, so
+/// any listed path with that file name counts, and so does a bare digest.
+/// 支持 GNU（`<hex>  name`、`<hex> *name`）和 BSD（`SHA256 (name) = <hex>`）格式的行。
+/// 摘要列表与压缩包位于同一目录，因此条目必须就是 `name`（可写作 `./name`），`sub/name`
+/// 是另一个文件。`single_file` 校验和文件以压缩包命名，因此文件名相同的任意路径都有效，
+/// 只包含摘要的行也有效。
+fn read_digest(path: &Path, algorithm: Algorithm, name: &str, single_file: bool) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let is_digest = |value: &str| {
+        value.len() == algorithm.hex_len() && value.chars().all(|c| c.is_ascii_hexdigit())
+    };
+    let lists_archive = |listed: &str| {
+        if single_file {
+            return Path::new(listed)
+                .file_name()
+                .is_some_and(|listed| listed == name);
+        }
+        let mut relative = listed;
+        while let Some(rest) = relative
+            .strip_prefix("./")
+            .or_else(|| relative.strip_prefix(".\\"))
+        {
+            relative = rest;
+        }
+        relative == name
+    };
+
+    content.lines().find_map(|line| {
+        let line = line.trim();
+        if let Some((label, digest)) = line.split_once(") = ") {
+            let listed = label.split_once(" (").map(|(_, listed)| listed)?;
+            let digest = digest.trim();
+            return (lists_archive(listed) && is_digest(digest)).then(|| digest.to_lowercase());
+        }
+
+        let (digest, listed) = match line.split_once(char::is_whitespace) {
+            Some((digest, listed)) => (digest, Some(listed.trim_start().trim_start_matches('*'))),
+            None => (line, None),
+        };
+        let name_matches = match listed {
+            Some(listed) => lists_archive(listed),
+            None => single_file,
+        };
+        (name_matches && is_digest(digest)).then(|| digest.to_lowercase())
+    })
+}
+
+/// Hashes `path` with `algorithm`, returning lowercase hex
+/// 使用 `algorithm` 计算 `path` 的摘要，返回小写十六进制
+fn hash_file<R: Runtime>(
+    app: &AppHandle<R>,
+    path: &Path,
+    algorithm: Algorithm,
+    operation_id: &str,
+) -> io::Result<String> {
+    match algorithm {
+        Algorithm::Sha256 => hash_with::<Sha256, R>(app, path, operation_id),
+        Algorithm::Sha1 => hash_with::<Sha1, R>(app, path, operation_id),
+        Algorithm::Md5 => hash_with::<Md5, R>(app, path, operation_id),
+    }
+}
+
+fn hash_with<D: Digest, R: Runtime>(
+    app: &AppHandle<R>,
+    path: &Path,
+    operation_id: &str,
+) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let total_bytes = file.metadata()?.len();
+    let archive_path = path.display().to_string();
+    let taskbar = app.state::<TaskbarProgress>();
+    let mut tracker = ProgressTracker::new(total_bytes);
+    let mut emit_progress = |processed_bytes| {
+        let progress = tracker.update(processed_bytes);
+        taskbar.update(app, operation_id, progress.percent.unwrap_or_default());
+        let _ = app.emit(
+            CHECKSUM_PROGRESS_EVENT,
+            ChecksumProgress {
+                operation_id: operation_id.to_string(),
+                archive_path: archive_path.clone(),
+                progress,
+            },
+        );
+    };
+
+    let mut hasher = D::new();
+    let mut buffer = vec![0u8; HASH_BUFFER_SIZE];
+    let mut processed_bytes = 0u64;
+    let mut last_progress = Instant::now();
+    loop {
+        let read = match file.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buffer[..read]);
+        processed_bytes += read as u64;
+        if last_progress.elapsed() >= PROGRESS_INTERVAL {
+            emit_progress(processed_bytes);
+            last_progress = Instant::now();
+        }
+    }
+    emit_progress(processed_bytes);
+
+    let mut hex = String::with_capacity(<D as Digest>::output_size() * 2);
+    for byte in hasher.finalize() {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    Ok(hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SHA256: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+    const OTHER_SHA256: &str = "60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752";
+
+    /// Empty folder for one test, removed on drop
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("soarzip-checksum-{}-{name}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        fn write(&self, name: &str, content: &str) -> PathBuf {
+            let path = self.0.join(name);
+            fs::write(&path, content).unwrap();
+            path
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn digest_in(content: &str, single_file: bool) -> Option<String> {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+        let dir = TestDir::new(&format!("digest-{}", NEXT.fetch_add(1, Ordering::Relaxed)));
+        let path = dir.write("list", content);
+        read_digest(&path, Algorithm::Sha256, "archive.7z", single_file)
+    }
+
+    #[test]
+    fn reads_gnu_lines() {
+        let text = format!("{SHA256}  archive.7z\n");
+        assert_eq!(digest_in(&text, false).as_deref(), Some(SHA256));
+        let binary = format!("{OTHER_SHA256} *other.7z\n{SHA256} *archive.7z\n");
+        assert_eq!(digest_in(&binary, false).as_deref(), Some(SHA256));
+    }
+
+    #[test]
+    fn reads_bsd_lines() {
+        let line = format!("SHA256 (archive.7z) = {}\n", SHA256.to_uppercase());
+        assert_eq!(digest_in(&line, false).as_deref(), Some(SHA256));
+    }
+
+    #[test]
+    fn single_file_sidecar_accepts_bare_digest_and_any_folder() {
+        assert_eq!(
+            digest_in(&format!("{SHA256}\n"), true).as_deref(),
+            Some(SHA256)
+        );
+        let built = format!("{SHA256}  /build/out/archive.7z\n");
+        assert_eq!(digest_in(&built, true).as_deref(), Some(SHA256));
+        assert_eq!(digest_in(&format!("{SHA256}\n"), false), None);
+    }
+
+    #[test]
+    fn list_entries_match_by_relative_path() {
+        let dotted = format!("{SHA256}  ./archive.7z\n");
+        assert_eq!(digest_in(&dotted, false).as_deref(), Some(SHA256));
+        let nested = format!("{SHA256}  sub/archive.7z\n");
+        assert_eq!(digest_in(&nested, false), None);
+        let bsd_nested = format!("SHA256 (sub/archive.7z) = {SHA256}\n");
+        assert_eq!(digest_in(&bsd_nested, false), None);
+    }
+
+    #[test]
+    fn ignores_other_entries_and_malformed_digests() {
+        let other = format!("{OTHER_SHA256}  other.7z\n");
+        assert_eq!(digest_in(&other, false), None);
+        let short = format!("{}  archive.7z\n", &SHA256[..40]);
+        assert_eq!(digest_in(&short, false), None);
+    }
+
+    #[test]
+    fn finds_per_file_sidecar_before_list() {
+        let dir = TestDir::new("find");
+        let archive = dir.write("archive.7z", "");
+        dir.write("SHA256SUMS", &format!("{OTHER_SHA256}  archive.7z\n"));
+        let sidecar = find_sidecar(&archive).unwrap();
+        assert_eq!(sidecar.expected, OTHER_SHA256);
+
+        dir.write("archive.7z.sha256", SHA256);
+        let sidecar = find_sidecar(&archive).unwrap();
+        assert_eq!(sidecar.expected, SHA256);
+        assert_eq!(sidecar.path, dir.0.join("archive.7z.sha256"));
+    }
+}
@@ -0,0 +1,59 @@
+//! Clipboard file list service
+//! 剪贴板文件列表服务
+//!
+//! Goes through arboard for both text and file references copied in the
+//! system file manager (CF_HDROP on Windows, file URLs on macOS and Linux).
+//! 文本以及在系统文件管理器中复制的文件引用（Windows 上的 CF_HDROP，
+//! macOS 和 Linux 上的文件 URL）都通过 arboard 处理。
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::models::error::AppError;
+
+/// Clipboard handle shared for the life of the process
+/// 在进程生命周期内共享的剪贴板句柄
+///
+/// On Linux the copied text is served by the process that set it and vanishes
+/// once its last `Clipboard` is dropped, so the handle is never dropped.
+/// 在 Linux 上，复制的文本由设置它的进程提供，最后一个 `Clipboard` 被释放后内容即消失，
+/// 因此该句柄永不释放。
+static CLIPBOARD: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+
+/// Returns the files and folders on the clipboard, empty when it holds something else
+/// 返回剪贴板上的文件和文件夹，剪贴板中为其他内容时返回空列表
+pub fn read_file_list() -> Result<Vec<PathBuf>, AppError> {
+    with_clipboard(|clipboard| match clipboard.get().file_list() {
+        Ok(paths) => Ok(paths
+            .into_iter()
+            .filter(|path| path.is_absolute())
+            .collect()),
+        Err(arboard::Error::ContentNotAvailable) => Ok(Vec::new()),
+        Err(e) => Err(e),
+    })
+}
+
+/// Replaces the clipboard contents with `text`
+/// 用 `text` 替换剪贴板内容
+pub fn write_text(text: String) -> Result<(), AppError> {
+    with_clipboard(|clipboard| clipboard.set_text(text))
+}
+
+fn with_clipboard<T>(
+    operation: impl FnOnce(&mut arboard::Clipboard) -> Result<T, arboard::Error>,
+) -> Result<T, AppError> {
+    let mut shared = CLIPBOARD
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let clipboard = match shared.as_mut() {
+        Some(clipboard) => clipboard,
+        None => shared.insert(arboard::Clipboard::new().map_err(clipboard_error)?),
+    };
+    operation(clipboard).map_err(clipboard_error)
+}
+
+fn clipboard_error(e: arboard::Error) -> AppError {
+    AppError::Clipboard {
+        message: e.to_string(),
+    }
+}
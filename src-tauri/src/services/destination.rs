@@ -0,0 +1,183 @@
+//! Extraction destination service
+//! 解压目标服务
+//!
+//! Decides where an archive is extracted when the user doesn't choose a folder.
+//! 决定用户未选择文件夹时压缩包的解压位置。
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::models::error::AppError;
+use crate::models::settings::{AppSettings, DestinationMode};
+use crate::utils::temp_dir;
+
+/// Highest number tried when deduplicating a folder name, as in `photos (999)`
+/// 对文件夹名称去重时尝试的最大编号，例如 `photos (999)`
+const MAX_FOLDER_NUMBER: u32 = 999;
+
+/// Resolves the default extraction directory for `archive_path`
+/// 解析 `archive_path` 的默认解压目录
+///
+/// Configured directories that no longer exist fall back to the archive's own folder.
+/// 已配置但不再存在的目录会回退到压缩包所在的文件夹。
+///
+/// # Arguments
+/// * `settings` - Current settings providing the mode and directories
+///   提供模式和目录的当前设置
+/// * `archive_path` - Archive about to be extracted
+///   即将解压的压缩包
+pub fn default_destination(settings: &AppSettings, archive_path: &Path) -> PathBuf {
+    let configured = match settings.destination_mode {
+        DestinationMode::ArchiveDirectory => None,
+        DestinationMode::DefaultDirectory => settings.default_extract_dir.as_deref(),
+        DestinationMode::LastUsed => settings.last_extract_dir.as_deref(),
+    };
+
+    configured
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_dir())
+        .unwrap_or_else(|| archive_directory(archive_path))
+}
+
+/// Creates `dir` if needed and proves it accepts new files before any work starts
+/// 在开始任何工作之前按需创建 `dir` 并确认其可以写入新文件
+///
+/// Fails with `InvalidArgument` when `dir` or one of its ancestors is a file,
+/// and with `PermissionDenied` when the folder or its volume is read-only.
+/// The probe is removed again right away.
+/// 当 `dir` 或其某个上级是文件时返回 `InvalidArgument`，当文件夹或其所在卷只读时返回
+/// `PermissionDenied`。探测内容会立即删除。
+pub fn prepare_output_directory(dir: &Path) -> Result<(), AppError> {
+    if let Some(existing) = dir.ancestors().find(|ancestor| ancestor.exists())
+        && !existing.is_dir()
+    {
+        return Err(AppError::InvalidArgument {
+            name: "output_dir".to_string(),
+            message: format!("{} is a file, not a folder", existing.display()),
+        });
+    }
+
+    fs::create_dir_all(dir).map_err(|e| AppError::io(dir, e))?;
+    temp_dir::probe_writable(dir).map_err(|e| AppError::io(dir, e))
+}
+
+/// Creates a folder called `name` in `parent`, numbering it `name (2)`, `name (3)`, … when taken
+/// 在 `parent` 中创建名为 `name` 的文件夹，名称已被占用时依次编号为 `name (2)`、`name (3)`……
+///
+/// New folders are claimed by creating them, never by checking first, so
+/// concurrent extractions can't end up in the same new folder. An existing
+/// empty folder is reused as is; that reuse is not exclusive.
+/// 新文件夹通过直接创建来占用，而不是先检查，因此并发的解压不会使用同一个新文件夹。
+/// 已存在的空文件夹会被直接复用，这种复用不是独占的。
+///
+/// # Returns
+/// The folder actually used
+/// 实际使用的文件夹
+pub fn create_unique_folder(parent: &Path, name: &str) -> Result<PathBuf, AppError> {
+    fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+
+    for number in 1..=MAX_FOLDER_NUMBER {
+        let candidate = match number {
+            1 => parent.join(name),
+            _ => parent.join(format!("{name} ({number})")),
+        };
+        match fs::create_dir(&candidate) {
+            Ok(()) => return Ok(candidate),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                let empty =
+                    fs::read_dir(&candidate).is_ok_and(|mut entries| entries.next().is_none());
+                if empty {
+                    return Ok(candidate);
+                }
+            }
+            Err(e) => return Err(AppError::io(&candidate, e)),
+        }
+    }
+
+    Err(AppError::InvalidArgument {
+        name: "output_dir".to_string(),
+        message: format!(
+            "{} already holds {MAX_FOLDER_NUMBER} non-empty folders named {name}",
+            parent.display()
+        ),
+    })
+}
+
+fn archive_directory(archive_path: &Path) -> PathBuf {
+    archive_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty parent folder for one test, removed on drop
+    struct TestDir(PathBuf);
+
+    impl TestDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("soarzip-destination-{}-{name}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+
+        /// Creates `name` as a folder holding one file
+        fn fill(&self, name: &str) {
+            let folder = self.0.join(name);
+            fs::create_dir_all(&folder).unwrap();
+            fs::write(folder.join("keep.txt"), b"").unwrap();
+        }
+    }
+
+    impl Drop for TestDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn creates_the_plain_name_first() {
+        let parent = TestDir::new("plain");
+        let folder = create_unique_folder(&parent.0, "photos").unwrap();
+        assert_eq!(folder, parent.0.join("photos"));
+        assert!(folder.is_dir());
+    }
+
+    #[test]
+    fn numbers_taken_names_in_sequence() {
+        let parent = TestDir::new("sequence");
+        parent.fill("photos");
+        let second = create_unique_folder(&parent.0, "photos").unwrap();
+        assert_eq!(second, parent.0.join("photos (2)"));
+
+        fs::write(second.join("keep.txt"), b"").unwrap();
+        let third = create_unique_folder(&parent.0, "photos").unwrap();
+        assert_eq!(third, parent.0.join("photos (3)"));
+    }
+
+    #[test]
+    fn reuses_an_empty_folder() {
+        let parent = TestDir::new("reuse");
+        fs::create_dir(parent.0.join("photos")).unwrap();
+        parent.fill("photos (2)");
+        let folder = create_unique_folder(&parent.0, "photos").unwrap();
+        assert_eq!(folder, parent.0.join("photos"));
+    }
+
+    #[test]
+    fn skips_names_taken_by_files_or_claimed_meanwhile() {
+        let parent = TestDir::new("taken");
+        // A file, like a folder another extraction filled between two attempts,
+        // makes create_dir fail with AlreadyExists and must not be reused
+        fs::write(parent.0.join("photos"), b"").unwrap();
+        parent.fill("photos (2)");
+        let folder = create_unique_folder(&parent.0, "photos").unwrap();
+        assert_eq!(folder, parent.0.join("photos (3)"));
+    }
+}
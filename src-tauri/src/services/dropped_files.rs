@@ -0,0 +1,44 @@
+//! Dropped file handling service
+//! 拖放文件处理服务
+//!
+//! Classifies paths dropped onto a window so the frontend can decide between
+//! opening an archive and adding files to the open one.
+//! 对拖放到窗口上的路径进行分类，以便前端决定是打开压缩包还是向已打开的压缩包添加文件。
+
+use std::path::PathBuf;
+
+use tauri::{Emitter, Runtime, Window};
+
+use crate::models::dropped_files::{DroppedFiles, DroppedItem};
+use crate::utils::archive_format;
+
+/// Event emitted to a window after files are dropped onto it
+/// 文件拖放到窗口后向该窗口发送的事件
+pub const FILES_DROPPED_EVENT: &str = "files-dropped";
+
+/// Classifies dropped or pasted paths as folders, archives or regular files
+/// 将拖放或粘贴的路径分类为文件夹、压缩包或普通文件
+pub fn classify(paths: &[PathBuf]) -> DroppedFiles {
+    let items = paths
+        .iter()
+        .map(|path| {
+            let is_dir = path.is_dir();
+            DroppedItem {
+                path: path.display().to_string(),
+                is_dir,
+                archive_format: (!is_dir)
+                    .then(|| archive_format::detect_or_guess_format(path))
+                    .flatten()
+                    .map(str::to_string),
+            }
+        })
+        .collect();
+
+    DroppedFiles { items }
+}
+
+/// Emits the classified drop to the window it happened on
+/// 将分类后的拖放结果发送给发生拖放的窗口
+pub fn emit_dropped<R: Runtime>(window: &Window<R>, paths: &[PathBuf]) {
+    let _ = window.emit_to(window.label(), FILES_DROPPED_EVENT, classify(paths));
+}
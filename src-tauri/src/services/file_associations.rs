@@ -0,0 +1,233 @@
+//! Windows file association service
+//! Windows 文件关联服务
+//!
+//! Registers SoarZip as the handler for archive extensions under
+//! `HKCU\Software\Classes`, so no elevation is ever needed.
+//! 在 `HKCU\Software\Classes` 下将 SoarZip 注册为压缩包扩展名的处理程序，因此无需提升权限。
+
+use crate::models::error::AppError;
+use crate::models::file_associations::AssociationResult;
+
+/// Normalizes a user-supplied extension to lowercase without a leading dot
+/// 将用户提供的扩展名规范化为不带前导点的小写形式
+///
+/// # Returns
+/// `None` when the extension contains anything but ASCII letters and digits
+/// 扩展名包含 ASCII 字母和数字以外的字符时返回 `None`
+pub fn normalize_extension(extension: &str) -> Option<String> {
+    let extension = extension
+        .trim()
+        .trim_start_matches('.')
+        .to_ascii_lowercase();
+    let valid = !extension.is_empty() && extension.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then_some(extension)
+}
+
+/// Registers the current executable as the handler for each extension
+/// 将当前可执行文件注册为每个扩展名的处理程序
+///
+/// # Returns
+/// Per-extension results; a failure on one extension doesn't stop the others
+/// 每个扩展名的结果；单个扩展名失败不会影响其他扩展名
+pub fn register(extensions: &[String]) -> Result<Vec<AssociationResult>, AppError> {
+    platform::register(extensions)
+}
+
+/// Removes SoarZip's registration for each extension
+/// 移除 SoarZip 对每个扩展名的注册
+pub fn unregister(extensions: &[String]) -> Result<Vec<AssociationResult>, AppError> {
+    platform::unregister(extensions)
+}
+
+/// Re-points existing registrations at the current executable if it has moved
+/// 如果可执行文件已移动，将已有的注册重新指向当前可执行文件
+pub fn repoint(extensions: &[String]) {
+    platform::repoint(extensions);
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ffi::OsString;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    use winreg::RegKey;
+    use winreg::enums::{HKEY_CURRENT_USER, KEY_ALL_ACCESS};
+
+    use crate::models::error::AppError;
+    use crate::models::file_associations::AssociationResult;
+
+    const CLASSES_KEY: &str = r"Software\Classes";
+
+    pub fn register(extensions: &[String]) -> Result<Vec<AssociationResult>, AppError> {
+        let exe = current_exe()?;
+        let results = to_results(extensions, |extension| register_extension(&exe, extension));
+        notify_shell();
+        Ok(results)
+    }
+
+    pub fn unregister(extensions: &[String]) -> Result<Vec<AssociationResult>, AppError> {
+        let results = to_results(extensions, unregister_extension);
+        notify_shell();
+        Ok(results)
+    }
+
+    pub fn repoint(extensions: &[String]) {
+        let Ok(exe) = current_exe() else {
+            return;
+        };
+
+        let expected = open_command(&exe);
+        let mut changed = false;
+        for extension in extensions {
+            let Ok(command_key) = classes().and_then(|classes| {
+                classes.open_subkey_with_flags(
+                    format!(r"{}\shell\open\command", prog_id(extension)),
+                    KEY_ALL_ACCESS,
+                )
+            }) else {
+                continue;
+            };
+
+            let current: OsString = command_key.get_value("").unwrap_or_default();
+            if current != expected && command_key.set_value("", &expected).is_ok() {
+                changed = true;
+            }
+        }
+
+        if changed {
+            notify_shell();
+        }
+    }
+
+    fn register_extension(exe: &Path, extension: &str) -> io::Result<()> {
+        let classes = classes()?;
+        let prog_id = prog_id(extension);
+
+        let (prog_key, _) = classes.create_subkey(&prog_id)?;
+        prog_key.set_value("", &format!("{} archive", extension.to_ascii_uppercase()))?;
+
+        let mut icon = OsString::from("\"");
+        icon.push(exe);
+        icon.push("\",0");
+        let (icon_key, _) = prog_key.create_subkey("DefaultIcon")?;
+        icon_key.set_value("", &icon)?;
+
+        let (command_key, _) = prog_key.create_subkey(r"shell\open\command")?;
+        command_key.set_value("", &open_command(exe))?;
+
+        let (extension_key, _) = classes.create_subkey(format!(".{extension}"))?;
+        extension_key.set_value("", &prog_id)?;
+        let (open_with_key, _) = extension_key.create_subkey("OpenWithProgids")?;
+        open_with_key.set_value(&prog_id, &"")?;
+        Ok(())
+    }
+
+    fn unregister_extension(extension: &str) -> io::Result<()> {
+        let classes = classes()?;
+        let prog_id = prog_id(extension);
+
+        match classes.delete_subkey_all(&prog_id) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+
+        let Ok(extension_key) =
+            classes.open_subkey_with_flags(format!(".{extension}"), KEY_ALL_ACCESS)
+        else {
+            return Ok(());
+        };
+        let default: String = extension_key.get_value("").unwrap_or_default();
+        if default == prog_id {
+            extension_key.delete_value("")?;
+        }
+        if let Ok(open_with_key) =
+            extension_key.open_subkey_with_flags("OpenWithProgids", KEY_ALL_ACCESS)
+        {
+            let _ = open_with_key.delete_value(&prog_id);
+        }
+        Ok(())
+    }
+
+    fn to_results(
+        extensions: &[String],
+        outcome: impl Fn(&str) -> std::io::Result<()>,
+    ) -> Vec<AssociationResult> {
+        extensions
+            .iter()
+            .map(|extension| match outcome(extension) {
+                Ok(()) => AssociationResult {
+                    extension: extension.clone(),
+                    success: true,
+                    error: None,
+                },
+                Err(e) => AssociationResult {
+                    extension: extension.clone(),
+                    success: false,
+                    error: Some(e.to_string()),
+                },
+            })
+            .collect()
+    }
+
+    fn classes() -> io::Result<RegKey> {
+        RegKey::predef(HKEY_CURRENT_USER)
+            .create_subkey(CLASSES_KEY)
+            .map(|(key, _)| key)
+    }
+
+    fn prog_id(extension: &str) -> String {
+        format!("SoarZip.{extension}")
+    }
+
+    /// Builds `"<exe>" "%1"`; both parts are quoted so paths with spaces survive
+    /// 构造 `"<exe>" "%1"`；两部分都加引号，以便包含空格的路径能正确传递
+    fn open_command(exe: &Path) -> OsString {
+        let mut command = OsString::from("\"");
+        command.push(exe);
+        command.push("\" \"%1\"");
+        command
+    }
+
+    fn current_exe() -> Result<PathBuf, AppError> {
+        std::env::current_exe().map_err(|e| AppError::io("current executable", e))
+    }
+
+    /// Tells Explorer to reload associations so icons and verbs update immediately
+    /// 通知资源管理器重新加载关联，使图标和操作立即更新
+    fn notify_shell() {
+        use windows_sys::Win32::UI::Shell::{SHCNE_ASSOCCHANGED, SHCNF_IDLIST, SHChangeNotify};
+
+        // SAFETY: SHCNE_ASSOCCHANGED takes no item pointers, so passing null is valid
+        unsafe {
+            SHChangeNotify(
+                SHCNE_ASSOCCHANGED as i32,
+                SHCNF_IDLIST,
+                std::ptr::null(),
+                std::ptr::null(),
+            );
+        }
+    }
+}
+
+#[cfg(not(windows))]
+mod platform {
+    use crate::models::error::AppError;
+    use crate::models::file_associations::AssociationResult;
+
+    pub fn register(_extensions: &[String]) -> Result<Vec<AssociationResult>, AppError> {
+        Err(unsupported())
+    }
+
+    pub fn unregister(_extensions: &[String]) -> Result<Vec<AssociationResult>, AppError> {
+        Err(unsupported())
+    }
+
+    pub fn repoint(_extensions: &[String]) {}
+
+    fn unsupported() -> AppError {
+        AppError::UnsupportedPlatform {
+            feature: "file associations".to_string(),
+        }
+    }
+}
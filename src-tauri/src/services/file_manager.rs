@@ -0,0 +1,200 @@
+//! File manager integration service
+//! 文件管理器集成服务
+//!
+//! Reveals files and folders in Explorer, Finder or the Linux desktop's file manager.
+//! 在资源管理器、访达或 Linux 桌面的文件管理器中显示文件和文件夹。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::models::error::AppError;
+use crate::utils::logger::log_debug;
+
+/// Minimum interval between two reveals of the same path
+/// 同一路径两次显示之间的最小间隔
+const REVEAL_DEBOUNCE: Duration = Duration::from_secs(3);
+
+/// How long to wait for the file manager to answer the D-Bus `ShowItems` call
+/// 等待文件管理器响应 D-Bus `ShowItems` 调用的时长
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const DBUS_REPLY_TIMEOUT_MS: u32 = 3000;
+
+/// Managed state that debounces reveals per destination
+/// 按目标路径对显示操作去抖的托管状态
+///
+/// Several extractions finishing close together into the same folder must not
+/// open a file manager window each.
+/// 多个解压操作几乎同时完成到同一文件夹时，不应各自打开一个文件管理器窗口。
+#[derive(Default)]
+pub struct RevealDebouncer {
+    last_reveals: Mutex<HashMap<PathBuf, Instant>>,
+}
+
+impl RevealDebouncer {
+    /// Claims a reveal of `path` and returns whether it should actually happen
+    /// 占用对 `path` 的显示请求，并返回是否应实际执行
+    ///
+    /// The claim is checked and taken under one lock so concurrent requests
+    /// can't both pass; a reveal that then fails must call `release`.
+    /// 检查和占用在同一把锁内完成，因此并发请求不会同时通过；之后显示失败时必须调用 `release`。
+    pub fn should_reveal(&self, path: &Path) -> bool {
+        let now = Instant::now();
+        let mut last_reveals = self.lock();
+
+        last_reveals.retain(|_, at| now.duration_since(*at) < REVEAL_DEBOUNCE);
+        if last_reveals.contains_key(path) {
+            return false;
+        }
+        last_reveals.insert(path.to_path_buf(), now);
+        true
+    }
+
+    /// Forgets the claim on `path` after a failed reveal so the user can retry at once
+    /// 显示失败后撤销对 `path` 的占用，以便用户立即重试
+    pub fn release(&self, path: &Path) {
+        self.lock().remove(path);
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<PathBuf, Instant>> {
+        self.last_reveals
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Opens the platform file manager with `path` selected
+/// 打开系统文件管理器并选中 `path`
+///
+/// Directories are opened directly; files are shown selected inside their parent folder.
+/// Only a reveal that succeeds counts for debouncing. May block for a few
+/// seconds on Linux, so call it off the main thread.
+/// 目录会被直接打开；文件会在其所在文件夹中被选中显示。只有成功的显示才计入去抖。
+/// 在 Linux 上可能阻塞数秒，因此应在主线程之外调用。
+///
+/// # Arguments
+/// * `path` - Existing file or directory to reveal
+///   要显示的已存在文件或目录
+/// * `debouncer` - Drops the request if the same path was revealed moments ago
+///   如果同一路径刚刚被显示过，则忽略此次请求
+pub fn reveal_in_file_manager(path: &Path, debouncer: &RevealDebouncer) -> Result<(), AppError> {
+    if !path.exists() {
+        return Err(AppError::NotFound {
+            path: path.display().to_string(),
+        });
+    }
+
+    if !debouncer.should_reveal(path) {
+        log_debug!("Skipping reveal of {}: shown moments ago", path.display());
+        return Ok(());
+    }
+
+    let result = reveal(path);
+    if result.is_err() {
+        debouncer.release(path);
+    }
+    result
+}
+
+#[cfg(target_os = "windows")]
+fn reveal(path: &Path) -> Result<(), AppError> {
+    use std::ffi::OsString;
+    use std::os::windows::process::CommandExt;
+
+    use crate::utils::paths;
+
+    // Explorer rejects verbatim `\\?\` paths, including `\\?\UNC\` shares
+    let path = paths::display_form(path);
+
+    // explorer.exe does its own command line parsing and does not understand the
+    // escaping std applies, so the quoted argument is passed through verbatim
+    let mut argument = OsString::new();
+    if !path.is_dir() {
+        argument.push("/select,");
+    }
+    argument.push("\"");
+    argument.push(path.as_os_str());
+    argument.push("\"");
+
+    let mut command = Command::new("explorer");
+    command.raw_arg(argument);
+    spawn_detached(command, "explorer")
+}
+
+#[cfg(target_os = "macos")]
+fn reveal(path: &Path) -> Result<(), AppError> {
+    let mut command = Command::new("open");
+    if !path.is_dir() {
+        command.arg("-R");
+    }
+    command.arg(path);
+    spawn_detached(command, "open")
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn reveal(path: &Path) -> Result<(), AppError> {
+    if path.is_dir() {
+        let mut command = Command::new("xdg-open");
+        command.arg(path);
+        return spawn_detached(command, "xdg-open");
+    }
+
+    // Most desktop file managers implement the FileManager1 D-Bus interface,
+    // which is the only portable way to get the file itself selected
+    let shown = Command::new("dbus-send")
+        .args([
+            "--session",
+            "--print-reply",
+            &format!("--reply-timeout={DBUS_REPLY_TIMEOUT_MS}"),
+            "--dest=org.freedesktop.FileManager1",
+            "/org/freedesktop/FileManager1",
+            "org.freedesktop.FileManager1.ShowItems",
+        ])
+        .arg(format!("array:string:{}", file_uri(path)))
+        .arg("string:")
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+
+    if shown {
+        return Ok(());
+    }
+
+    let parent = path.parent().unwrap_or(path);
+    let mut command = Command::new("xdg-open");
+    command.arg(parent);
+    spawn_detached(command, "xdg-open")
+}
+
+/// Builds a percent-encoded `file://` URI for D-Bus consumers
+/// 为 D-Bus 调用构造百分号编码的 `file://` URI
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn file_uri(path: &Path) -> String {
+    use std::fmt::Write;
+    use std::os::unix::ffi::OsStrExt;
+
+    let mut uri = String::from("file://");
+    for &byte in path.as_os_str().as_bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            uri.push(byte as char);
+        } else {
+            let _ = write!(uri, "%{byte:02X}");
+        }
+    }
+    uri
+}
+
+/// Starts `command` without waiting for it; a background thread reaps it so no zombie is left
+/// 启动 `command` 而不等待其结束；由后台线程回收，以免留下僵尸进程
+fn spawn_detached(mut command: Command, program: &str) -> Result<(), AppError> {
+    let mut child = command.spawn().map_err(|e| AppError::ProcessLaunch {
+        program: program.to_string(),
+        message: e.to_string(),
+    })?;
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
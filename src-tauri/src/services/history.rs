@@ -0,0 +1,121 @@
+//! Operation history service
+//! 操作历史服务
+//!
+//! Keeps a capped JSON-lines record of finished operations in the app data
+//! directory. Entries are written on a background thread so recording never
+//! delays an operation's result.
+//! 在应用数据目录中以 JSON-lines 格式保存已完成操作的记录，并限制其数量。
+//! 条目在后台线程中写入，因此记录操作不会延迟操作结果的返回。
+
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::models::error::AppError;
+use crate::models::history::{HistoryEntry, HistoryFilter};
+use crate::utils::json_file;
+use crate::utils::logger::log_warn;
+use crate::utils::time;
+
+/// File name of the history inside the app data directory
+/// 应用数据目录中历史记录的文件名
+pub const HISTORY_FILE_NAME: &str = "operation_history.jsonl";
+
+/// Maximum number of entries kept
+/// 保留的最大条目数
+const MAX_HISTORY_ENTRIES: usize = 1000;
+
+/// Entries older than this are dropped
+/// 超过此时长的条目会被删除
+const MAX_HISTORY_AGE: Duration = Duration::from_secs(90 * 24 * 60 * 60);
+
+/// Managed state guarding the history file
+/// 保护历史记录文件的托管状态
+pub struct OperationHistory {
+    file: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl OperationHistory {
+    /// Uses `file` as the history file; nothing is read until it is needed
+    /// 使用 `file` 作为历史记录文件；在需要之前不会读取
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Appends `entry`, dropping entries beyond the count and age caps
+    /// 追加 `entry`，并删除超出数量和时间上限的条目
+    pub fn append(&self, entry: HistoryEntry) -> Result<(), AppError> {
+        let _guard = self.lock();
+        let mut entries = self.read()?;
+        entries.push(entry);
+
+        let oldest = time::unix_now().saturating_sub(MAX_HISTORY_AGE.as_secs());
+        entries.retain(|entry| entry.timestamp >= oldest);
+        let overflow = entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        entries.drain(..overflow);
+
+        json_file::write_json_lines(&self.file, &entries).map_err(|e| AppError::io(&self.file, e))
+    }
+
+    /// Returns up to `limit` entries matching `filter`, newest first
+    /// 返回最多 `limit` 条匹配 `filter` 的条目，最新的在前
+    pub fn list(
+        &self,
+        filter: &HistoryFilter,
+        limit: usize,
+    ) -> Result<Vec<HistoryEntry>, AppError> {
+        let _guard = self.lock();
+        let entries = self.read()?;
+        Ok(entries
+            .into_iter()
+            .rev()
+            .filter(|entry| {
+                filter.kind.as_ref().is_none_or(|kind| &entry.kind == kind)
+                    && filter
+                        .archive_path
+                        .as_ref()
+                        .is_none_or(|path| &entry.archive_path == path)
+                    && filter.since.is_none_or(|since| entry.timestamp >= since)
+                    && filter
+                        .outcome
+                        .is_none_or(|outcome| entry.outcome == outcome)
+            })
+            .take(limit)
+            .collect())
+    }
+
+    /// Deletes all entries
+    /// 删除所有条目
+    pub fn clear(&self) -> Result<(), AppError> {
+        let _guard = self.lock();
+        json_file::write_json_lines::<HistoryEntry>(&self.file, &[])
+            .map_err(|e| AppError::io(&self.file, e))
+    }
+
+    fn read(&self) -> Result<Vec<HistoryEntry>, AppError> {
+        json_file::read_json_lines(&self.file).map_err(|e| AppError::io(&self.file, e))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ()> {
+        self.lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Records a finished operation on a background thread
+/// 在后台线程中记录一次已完成的操作
+pub fn record<R: Runtime>(app: &AppHandle<R>, entry: HistoryEntry) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = app.state::<OperationHistory>().append(entry) {
+            log_warn!("Failed to record operation history: {e}");
+        }
+    });
+}
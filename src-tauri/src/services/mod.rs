@@ -0,0 +1,21 @@
+//! Business logic services used by the Tauri commands
+//! Tauri 命令使用的业务逻辑服务
+
+pub mod checksum;
+pub mod clipboard;
+pub mod destination;
+pub mod dropped_files;
+pub mod file_associations;
+pub mod file_manager;
+pub mod history;
+pub mod notifications;
+pub mod open_requests;
+pub mod operations;
+pub mod profiles;
+pub mod recent;
+pub mod settings;
+pub mod taskbar;
+pub mod updates;
+pub mod volumes;
+pub mod window_manager;
+pub mod window_state;
@@ -0,0 +1,68 @@
+//! Completion notification service
+//! 完成通知服务
+//!
+//! Sends a desktop notification when a long operation finishes while the user
+//! is working in another application.
+//! 当长时间运行的操作完成而用户正在使用其他应用程序时，发送桌面通知。
+
+use std::path::Path;
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::models::history::OperationOutcome;
+use crate::services::settings::SettingsStore;
+use crate::utils::logger::log_warn;
+
+/// Notifies about a finished operation if it was long and no SoarZip window has focus
+/// 如果操作耗时较长且没有 SoarZip 窗口处于焦点，则通知操作已完成
+///
+/// # Arguments
+/// * `archive` - Archive the operation worked on, shown by file name
+///   操作所针对的压缩包，以文件名显示
+/// * `outcome` - How the operation ended
+///   操作的结束方式
+/// * `elapsed` - How long the operation ran
+///   操作运行的时长
+pub fn notify_completion<R: Runtime>(
+    app: &AppHandle<R>,
+    archive: &Path,
+    outcome: OperationOutcome,
+    elapsed: Duration,
+) {
+    let settings = app.state::<SettingsStore>().get();
+    if !settings.notify_on_completion
+        || elapsed < Duration::from_secs(settings.notification_threshold_secs)
+    {
+        return;
+    }
+
+    let focused = app
+        .webview_windows()
+        .values()
+        .any(|window| window.is_focused().unwrap_or(false));
+    if focused {
+        return;
+    }
+
+    let name = archive
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| archive.display().to_string());
+    let body = match outcome {
+        OperationOutcome::Success => format!("{name}: completed"),
+        OperationOutcome::Warning => format!("{name}: completed with warnings"),
+        OperationOutcome::Failure => format!("{name}: failed"),
+    };
+
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title(app.package_info().name.clone())
+        .body(body)
+        .show()
+    {
+        log_warn!("Failed to show completion notification: {e}");
+    }
+}
@@ -0,0 +1,164 @@
+//! Archive open request service
+//! 压缩包打开请求服务
+//!
+//! Turns archive paths handed to the app by the OS (command line, a second
+//! launch, macOS file-open events) into `open-archive-request` events for the
+//! frontend. Paths are buffered until the main window's frontend reports it is
+//! ready, so a request arriving during startup is never lost.
+//! 将操作系统交给应用的压缩包路径（命令行、二次启动、macOS 文件打开事件）转换为发送给前端的
+//! `open-archive-request` 事件。路径会被缓存，直到主窗口前端报告已就绪，
+//! 因此启动期间到达的请求不会丢失。
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+use crate::services::settings::SettingsStore;
+use crate::services::window_manager::{self, WindowArchives};
+use crate::utils::archive_format;
+
+/// Event asking a window's frontend to open an archive
+/// 请求窗口前端打开压缩包的事件
+pub const OPEN_ARCHIVE_EVENT: &str = "open-archive-request";
+
+/// Payload of `open-archive-request`
+/// `open-archive-request` 的负载
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenArchiveRequest {
+    /// Absolute path of the archive to open
+    /// 要打开的压缩包的绝对路径
+    pub path: String,
+}
+
+#[derive(Default)]
+struct QueueState {
+    frontend_ready: bool,
+    pending: VecDeque<String>,
+}
+
+/// Managed state buffering archive paths for the main window
+/// 为主窗口缓存压缩包路径的托管状态
+#[derive(Default)]
+pub struct OpenRequestQueue {
+    state: Mutex<QueueState>,
+}
+
+impl OpenRequestQueue {
+    /// Queues archives for the main window, delivering the first one right away if it is ready
+    /// 为主窗口排队压缩包，若主窗口已就绪则立即投递第一个
+    pub fn enqueue<R: Runtime>(&self, app: &AppHandle<R>, paths: Vec<PathBuf>) {
+        let mut state = self.lock();
+        let mut paths = paths.into_iter().map(|path| path.display().to_string());
+
+        if state.frontend_ready
+            && let Some(path) = paths.next()
+        {
+            emit_request(app, path);
+        }
+        state.pending.extend(paths);
+    }
+
+    /// Marks the main window's frontend as ready and delivers the first buffered archive
+    /// 将主窗口前端标记为已就绪并投递第一个缓存的压缩包
+    pub fn mark_ready<R: Runtime>(&self, app: &AppHandle<R>) {
+        let mut state = self.lock();
+        state.frontend_ready = true;
+        if let Some(path) = state.pending.pop_front() {
+            emit_request(app, path);
+        }
+    }
+
+    /// Removes and returns the next queued archive, if any
+    /// 移除并返回下一个排队的压缩包（如有）
+    pub fn take_next(&self) -> Option<String> {
+        self.lock().pending.pop_front()
+    }
+
+    /// Number of archives still waiting for the main window
+    /// 仍在等待主窗口的压缩包数
+    pub fn pending_count(&self) -> usize {
+        self.lock().pending.len()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QueueState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn emit_request<R: Runtime>(app: &AppHandle<R>, path: String) {
+    let _ = app.emit_to("main", OPEN_ARCHIVE_EVENT, OpenArchiveRequest { path });
+}
+
+/// Extracts the archive arguments from a command line
+/// 从命令行中提取压缩包参数
+///
+/// The program name and flags are skipped, relative paths are resolved against
+/// `cwd`, and only existing files recognized as archives are kept.
+/// 跳过程序名和选项，相对路径基于 `cwd` 解析，只保留存在且被识别为压缩包的文件。
+///
+/// # Arguments
+/// * `args` - Full argv including the program name
+///   包含程序名的完整 argv
+/// * `cwd` - Directory relative paths are resolved against
+///   解析相对路径所基于的目录
+pub fn archive_paths_from_args(args: &[String], cwd: &Path) -> Vec<PathBuf> {
+    args.iter()
+        .skip(1)
+        .filter(|arg| !arg.starts_with('-'))
+        .map(|arg| cwd.join(arg))
+        .filter(|path| is_openable_archive(path))
+        .collect()
+}
+
+/// Whether `path` is an existing file whose content or extension marks it as an archive
+/// `path` 是否为内容或扩展名表明其为压缩包的已存在文件
+pub fn is_openable_archive(path: &Path) -> bool {
+    path.is_file() && archive_format::detect_or_guess_format(path).is_some()
+}
+
+/// Handles a launch attempt forwarded from a second instance
+/// 处理从第二个实例转发过来的启动请求
+///
+/// Archives go to the main window, or each to its own new window when the
+/// `open_forwarded_in_new_window` setting is on; an archive whose window can't
+/// be created is queued for the main window instead. The last target window is
+/// then focused. Before the settings are loaded everything goes to the main window.
+/// 压缩包会发送到主窗口；若开启了 `open_forwarded_in_new_window` 设置，则每个压缩包都在各自的新窗口中打开，
+/// 无法创建窗口的压缩包改为排队发送到主窗口。随后聚焦最后一个目标窗口。
+/// 设置加载完成之前，所有压缩包都发送到主窗口。
+pub fn handle_second_instance<R: Runtime>(app: &AppHandle<R>, args: &[String], cwd: &str) {
+    let paths = archive_paths_from_args(args, Path::new(cwd));
+    let new_windows = app
+        .try_state::<SettingsStore>()
+        .is_some_and(|settings| settings.get().open_forwarded_in_new_window);
+
+    let mut label = Some("main".to_string());
+    if new_windows && !paths.is_empty() {
+        let windows = app.state::<WindowArchives>();
+        let mut for_main = Vec::new();
+        for path in paths {
+            match window_manager::open_window(app, &windows, Some(path.display().to_string())) {
+                Ok(opened) => label = Some(opened),
+                // open_window has already logged the failure
+                Err(_) => for_main.push(path),
+            }
+        }
+        if !for_main.is_empty() {
+            app.state::<OpenRequestQueue>().enqueue(app, for_main);
+            label = Some("main".to_string());
+        }
+    } else {
+        app.state::<OpenRequestQueue>().enqueue(app, paths);
+    }
+
+    if let Some(window) = label.and_then(|label| app.get_webview_window(&label)) {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
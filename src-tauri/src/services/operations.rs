@@ -0,0 +1,90 @@
+//! Active operation registry
+//! 活动操作注册表
+//!
+//! Tracks every long-running operation from start to finish, whichever window
+//! started it and whether or not it reports progress, so diagnostics can list
+//! what is running.
+//! 跟踪每个长时间运行的操作从开始到结束的过程，无论由哪个窗口发起、是否报告进度，
+//! 以便诊断功能列出正在运行的内容。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Instant;
+
+use crate::models::diagnostics::ActiveOperation;
+
+/// A registered operation
+/// 已登记的操作
+struct RunningOperation {
+    kind: &'static str,
+    started: Instant,
+}
+
+type Registry = Arc<Mutex<HashMap<String, RunningOperation>>>;
+
+/// Managed state holding the operations that are currently running
+/// 保存当前正在运行的操作的托管状态
+#[derive(Default)]
+pub struct ActiveOperations {
+    running: Registry,
+}
+
+impl ActiveOperations {
+    /// Registers `operation_id` until the returned guard is dropped
+    /// 登记 `operation_id`，直到返回的守卫被 drop
+    ///
+    /// # Arguments
+    /// * `operation_id` - Id used in progress events and log records
+    ///   进度事件和日志记录中使用的 ID
+    /// * `kind` - Operation kind, as recorded in the history, e.g. "verify"
+    ///   操作类型，与历史记录中的一致，例如 "verify"
+    pub fn begin(&self, operation_id: &str, kind: &'static str) -> OperationGuard {
+        lock(&self.running).insert(
+            operation_id.to_string(),
+            RunningOperation {
+                kind,
+                started: Instant::now(),
+            },
+        );
+        OperationGuard {
+            running: Arc::clone(&self.running),
+            operation_id: operation_id.to_string(),
+        }
+    }
+
+    /// Lists the running operations, oldest first
+    /// 列出运行中的操作，最早开始的在前
+    pub fn list(&self) -> Vec<ActiveOperation> {
+        let running = lock(&self.running);
+        let mut operations: Vec<(&String, &RunningOperation)> = running.iter().collect();
+        operations.sort_by_key(|(_, operation)| operation.started);
+        operations
+            .into_iter()
+            .map(|(id, operation)| ActiveOperation {
+                id: id.clone(),
+                kind: operation.kind.to_string(),
+                age_secs: operation.started.elapsed().as_secs(),
+            })
+            .collect()
+    }
+}
+
+/// Keeps an operation registered; dropping it, also on early returns, unregisters it
+/// 保持操作处于登记状态；drop 时（包括提前返回时）注销该操作
+#[must_use = "the operation is unregistered as soon as the guard is dropped"]
+pub struct OperationGuard {
+    running: Registry,
+    operation_id: String,
+}
+
+impl Drop for OperationGuard {
+    fn drop(&mut self) {
+        lock(&self.running).remove(&self.operation_id);
+    }
+}
+
+fn lock(running: &Registry) -> MutexGuard<'_, HashMap<String, RunningOperation>> {
+    running
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
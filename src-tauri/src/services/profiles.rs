@@ -0,0 +1,142 @@
+//! Compression profile service
+//! 压缩配置方案服务
+//!
+//! User profiles are stored in the settings file next to two read-only
+//! built-in profiles that always exist.
+//! 用户配置方案保存在设置文件中，另有两个始终存在的只读内置配置方案。
+
+use crate::models::error::AppError;
+use crate::models::profiles::{CompressionProfile, ProfileEntry};
+use crate::services::settings::SettingsStore;
+use crate::utils::archive_format;
+
+/// Built-in profiles shipped with the app
+/// 随应用提供的内置配置方案
+fn built_in_profiles() -> [(&'static str, CompressionProfile); 2] {
+    [
+        (
+            "Fast zip",
+            CompressionProfile {
+                archive_type: "zip".to_string(),
+                compression_level: 1,
+                ..CompressionProfile::default()
+            },
+        ),
+        (
+            "Max 7z",
+            CompressionProfile {
+                archive_type: "7z".to_string(),
+                compression_level: 9,
+                ..CompressionProfile::default()
+            },
+        ),
+    ]
+}
+
+/// Whether `name` is taken by a built-in profile, ignoring ASCII case
+/// `name` 是否已被内置配置方案使用（忽略 ASCII 大小写）
+pub fn is_built_in(name: &str) -> bool {
+    built_in_profiles()
+        .iter()
+        .any(|(built_in, _)| built_in.eq_ignore_ascii_case(name))
+}
+
+/// Returns the built-in profiles followed by the user's, sorted by name
+/// 返回内置配置方案，随后是按名称排序的用户配置方案
+pub fn list(settings: &SettingsStore) -> Vec<ProfileEntry> {
+    let built_in = built_in_profiles()
+        .into_iter()
+        .map(|(name, profile)| ProfileEntry {
+            name: name.to_string(),
+            built_in: true,
+            profile,
+        });
+    let user = settings
+        .get()
+        .compression_profiles
+        .into_iter()
+        .map(|(name, profile)| ProfileEntry {
+            name,
+            built_in: false,
+            profile,
+        });
+    built_in.chain(user).collect()
+}
+
+/// Creates or replaces the user profile `name`
+/// 创建或替换名为 `name` 的用户配置方案
+pub fn save(
+    settings: &SettingsStore,
+    name: &str,
+    profile: CompressionProfile,
+) -> Result<(), AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::InvalidArgument {
+            name: "name".to_string(),
+            message: "profile name must not be empty".to_string(),
+        });
+    }
+    if is_built_in(name) {
+        return Err(AppError::InvalidArgument {
+            name: "name".to_string(),
+            message: format!("{name} is a built-in profile"),
+        });
+    }
+    check_profile(&profile)?;
+
+    settings.update_with(|settings| {
+        settings
+            .compression_profiles
+            .insert(name.to_string(), profile);
+    })
+}
+
+/// Deletes the user profile `name`; built-in profiles cannot be deleted
+/// 删除名为 `name` 的用户配置方案；内置配置方案不能删除
+pub fn delete(settings: &SettingsStore, name: &str) -> Result<(), AppError> {
+    if is_built_in(name) {
+        return Err(AppError::InvalidArgument {
+            name: "name".to_string(),
+            message: format!("{name} is a built-in profile and cannot be deleted"),
+        });
+    }
+    if !settings.get().compression_profiles.contains_key(name) {
+        return Err(AppError::InvalidArgument {
+            name: "name".to_string(),
+            message: format!("no profile named {name}"),
+        });
+    }
+
+    settings.update_with(|settings| {
+        settings.compression_profiles.remove(name);
+    })
+}
+
+/// Checks a profile's values
+/// 检查配置方案的取值
+///
+/// Fails with `InvalidArgument` naming the offending field.
+/// 失败时返回指明出错字段的 `InvalidArgument`。
+pub fn check_profile(profile: &CompressionProfile) -> Result<(), AppError> {
+    let invalid = |name: &str, message: String| {
+        Err(AppError::InvalidArgument {
+            name: name.to_string(),
+            message,
+        })
+    };
+
+    if profile.compression_level > 9 {
+        return invalid("compression_level", "must be between 0 and 9".to_string());
+    }
+    if !archive_format::is_creatable_format(&profile.archive_type) {
+        return invalid(
+            "archive_type",
+            format!("unsupported archive type {}", profile.archive_type),
+        );
+    }
+    if profile.volume_size == Some(0) {
+        return invalid("volume_size", "must be greater than zero".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,290 @@
+//! Recent archives service
+//! 最近打开的压缩包服务
+//!
+//! Persists the recent list as JSON in the app data directory.
+//! 将最近列表以 JSON 形式保存在应用数据目录中。
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::error::AppError;
+use crate::models::recent::{RecentArchive, RecentArchivesList};
+use crate::utils::json_file;
+use crate::utils::logger::log_warn;
+use crate::utils::time;
+
+/// File name of the recent list inside the app data directory
+/// 应用数据目录中最近列表的文件名
+pub const RECENT_FILE_NAME: &str = "recent_archives.json";
+
+/// Maximum number of unpinned entries kept in the recent list
+/// 最近列表中保留的未固定条目的最大数量
+const MAX_RECENT_ARCHIVES: usize = 30;
+
+/// Persisted form of a recent entry
+/// 最近条目的持久化形式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecentRecord {
+    path: String,
+    last_opened: u64,
+    #[serde(default)]
+    pinned: bool,
+}
+
+/// Managed state holding the recent archives list
+/// 保存最近压缩包列表的托管状态
+pub struct RecentArchives {
+    file: PathBuf,
+    records: Mutex<Vec<RecentRecord>>,
+}
+
+impl RecentArchives {
+    /// Loads the list from `file`, starting empty when it is missing or unreadable
+    /// 从 `file` 加载列表，文件不存在或无法读取时从空列表开始
+    pub fn load(file: PathBuf) -> Self {
+        let records = json_file::read_json(&file).unwrap_or_else(|e| {
+            log_warn!("Ignoring unreadable recent list {}: {e}", file.display());
+            None
+        });
+        let records = records.unwrap_or_default();
+        Self {
+            file,
+            records: Mutex::new(records),
+        }
+    }
+
+    /// Moves `path` to the top of the list, adding it if needed
+    /// 将 `path` 移动到列表顶部，必要时添加
+    pub fn add(&self, path: &str) -> Result<(), AppError> {
+        let mut records = self.lock();
+        let pinned = records
+            .iter()
+            .any(|record| record.path == path && record.pinned);
+        records.retain(|record| record.path != path);
+        records.insert(
+            0,
+            RecentRecord {
+                path: path.to_string(),
+                last_opened: time::unix_now(),
+                pinned,
+            },
+        );
+        evict_overflow(&mut records);
+        self.save(&records)
+    }
+
+    /// Pins or unpins `path`, adding it to the list when it is not present
+    /// 固定或取消固定 `path`，不在列表中时将其添加
+    pub fn set_pinned(&self, path: &str, pinned: bool) -> Result<(), AppError> {
+        let mut records = self.lock();
+        match records.iter_mut().find(|record| record.path == path) {
+            Some(record) => record.pinned = pinned,
+            None => records.insert(
+                0,
+                RecentRecord {
+                    path: path.to_string(),
+                    last_opened: time::unix_now(),
+                    pinned,
+                },
+            ),
+        }
+        evict_overflow(&mut records);
+        self.save(&records)
+    }
+
+    /// Returns all pinned entries and up to `limit` unpinned ones, most recent first
+    /// 返回所有固定条目以及最多 `limit` 个未固定条目，最近的在前
+    ///
+    /// Unpinned entries whose files have disappeared are returned once with
+    /// `missing` set and then pruned from the store; entries beyond `limit` are
+    /// left alone until they are returned. Pinned entries are only flagged,
+    /// since their drive may simply be disconnected.
+    /// 文件已消失的未固定条目会带 `missing` 标记返回一次，随后从存储中移除；
+    /// 超出 `limit` 的条目在被返回之前保持不变。固定条目仅被标记，因为其所在驱动器可能只是暂时断开。
+    pub fn list(&self, limit: usize) -> Result<RecentArchivesList, AppError> {
+        let mut records = self.lock();
+        let pinned = records
+            .iter()
+            .filter(|record| record.pinned)
+            .map(to_recent_archive)
+            .collect();
+        let recent: Vec<RecentArchive> = records
+            .iter()
+            .filter(|record| !record.pinned)
+            .take(limit)
+            .map(to_recent_archive)
+            .collect();
+
+        let shown_missing: HashSet<&str> = recent
+            .iter()
+            .filter(|archive| archive.missing)
+            .map(|archive| archive.path.as_str())
+            .collect();
+        if !shown_missing.is_empty() {
+            records.retain(|record| record.pinned || !shown_missing.contains(record.path.as_str()));
+            self.save(&records)?;
+        }
+
+        Ok(RecentArchivesList { pinned, recent })
+    }
+
+    /// Removes `path` from the list
+    /// 从列表中移除 `path`
+    pub fn remove(&self, path: &str) -> Result<(), AppError> {
+        let mut records = self.lock();
+        records.retain(|record| record.path != path);
+        self.save(&records)
+    }
+
+    /// Empties the list, keeping pinned entries unless `including_pinned` is set
+    /// 清空列表，除非设置了 `including_pinned`，否则保留固定条目
+    pub fn clear(&self, including_pinned: bool) -> Result<(), AppError> {
+        let mut records = self.lock();
+        records.retain(|record| record.pinned && !including_pinned);
+        self.save(&records)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<RecentRecord>> {
+        self.records
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn save(&self, records: &[RecentRecord]) -> Result<(), AppError> {
+        json_file::write_json(&self.file, &records).map_err(|e| AppError::io(&self.file, e))
+    }
+}
+
+/// Drops the oldest unpinned entries beyond the cap; pinned entries never count
+/// 删除超出上限的最旧未固定条目；固定条目不计入上限
+fn evict_overflow(records: &mut Vec<RecentRecord>) {
+    let mut unpinned = 0;
+    records.retain(|record| {
+        if record.pinned {
+            return true;
+        }
+        unpinned += 1;
+        unpinned <= MAX_RECENT_ARCHIVES
+    });
+}
+
+fn to_recent_archive(record: &RecentRecord) -> RecentArchive {
+    let path = Path::new(&record.path);
+    let size = path.metadata().ok().map(|metadata| metadata.len());
+    RecentArchive {
+        path: record.path.clone(),
+        file_name: path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| record.path.clone()),
+        size,
+        last_opened: record.last_opened,
+        pinned: record.pinned,
+        missing: size.is_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use crate::utils::paths;
+
+    fn store(name: &str) -> RecentArchives {
+        let file =
+            std::env::temp_dir().join(format!("soarzip-recent-{}-{name}.json", std::process::id()));
+        let _ = fs::remove_file(&file);
+        RecentArchives::load(file)
+    }
+
+    fn missing_path(index: usize) -> String {
+        std::env::temp_dir()
+            .join(format!("soarzip-missing-{}-{index}.7z", std::process::id()))
+            .display()
+            .to_string()
+    }
+
+    #[test]
+    fn missing_entries_beyond_limit_are_kept_until_shown() {
+        let recent = store("beyond-limit");
+        for index in 0..5 {
+            recent.add(&missing_path(index)).unwrap();
+        }
+
+        let first = recent.list(2).unwrap();
+        assert_eq!(first.recent.len(), 2);
+        assert!(first.recent.iter().all(|archive| archive.missing));
+        assert_eq!(recent.lock().len(), 3);
+
+        let second = recent.list(2).unwrap();
+        assert_eq!(
+            second
+                .recent
+                .iter()
+                .map(|archive| archive.path.clone())
+                .collect::<Vec<_>>(),
+            vec![missing_path(2), missing_path(1)]
+        );
+        assert_eq!(recent.lock().len(), 1);
+
+        let _ = fs::remove_file(&recent.file);
+    }
+
+    /// Records `raw` the way `add_recent_archive` does
+    fn add_resolved(recent: &RecentArchives, raw: &str) {
+        let path = paths::resolve_user_path("path", raw, None).unwrap();
+        recent.add(&path.display().to_string()).unwrap();
+    }
+
+    #[test]
+    fn quoted_and_trailing_separator_forms_share_one_entry() {
+        let recent = store("dedupe");
+        let plain = missing_path(20);
+        add_resolved(&recent, &format!("\"{plain}\""));
+        add_resolved(&recent, &format!("{plain}{}", std::path::MAIN_SEPARATOR));
+        add_resolved(&recent, &plain);
+        assert_eq!(recent.lock().len(), 1);
+
+        let _ = fs::remove_file(&recent.file);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn windows_path_forms_share_one_entry() {
+        let recent = store("dedupe-windows");
+        add_resolved(&recent, "\"C:\\x.7z\"");
+        add_resolved(&recent, "C:\\x.7z\\");
+        assert_eq!(recent.lock().len(), 1);
+        assert_eq!(recent.lock()[0].path, "C:\\x.7z");
+
+        let _ = fs::remove_file(&recent.file);
+    }
+
+    #[test]
+    fn existing_and_pinned_entries_are_never_pruned() {
+        let recent = store("kept");
+        let existing = std::env::temp_dir().display().to_string();
+        recent.add(&existing).unwrap();
+        recent.set_pinned(&missing_path(10), true).unwrap();
+        recent.add(&missing_path(11)).unwrap();
+
+        let list = recent.list(10).unwrap();
+        assert_eq!(list.pinned.len(), 1);
+        assert!(list.pinned[0].missing);
+        assert_eq!(list.recent.len(), 2);
+
+        let remaining: Vec<String> = recent
+            .lock()
+            .iter()
+            .map(|record| record.path.clone())
+            .collect();
+        assert_eq!(remaining, vec![missing_path(10), existing]);
+
+        let _ = fs::remove_file(&recent.file);
+    }
+}
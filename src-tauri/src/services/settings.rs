@@ -0,0 +1,263 @@
+//! Settings persistence service
+//! 设置持久化服务
+//!
+//! Settings are loaded once at startup into managed state and written back as
+//! JSON in the app config directory on every update.
+//! 设置在启动时加载到托管状态中，每次更新时以 JSON 形式写回应用配置目录。
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde_json::Value;
+
+use crate::models::error::AppError;
+use crate::models::settings::AppSettings;
+use crate::services::profiles;
+use crate::utils::archive_format;
+use crate::utils::json_file;
+use crate::utils::logger::{self, log_error, log_warn};
+use crate::utils::time;
+
+/// File name of the settings file inside the app config directory
+/// 应用配置目录中设置文件的文件名
+pub const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// Managed state holding the current settings
+/// 保存当前设置的托管状态
+pub struct SettingsStore {
+    file: PathBuf,
+    settings: Mutex<AppSettings>,
+}
+
+impl SettingsStore {
+    /// Loads settings from `file`
+    /// 从 `file` 加载设置
+    ///
+    /// A missing file yields defaults. A file that doesn't parse is moved aside
+    /// to a `.corrupt-<timestamp>` backup and replaced with defaults so startup
+    /// never fails. A file that can't be read right now, e.g. while a scanner
+    /// holds it or for lack of permission, is left untouched and defaults are
+    /// used in memory only.
+    /// 文件不存在时使用默认值。无法解析的文件会被移到 `.corrupt-<时间戳>` 备份并替换为默认值，
+    /// 以确保启动不会失败。暂时无法读取的文件（例如被扫描程序占用或缺少权限）保持不变，
+    /// 仅在内存中使用默认值。
+    pub fn load(file: PathBuf) -> Self {
+        let settings = match json_file::read_json::<AppSettings>(&file) {
+            Ok(settings) => settings.unwrap_or_default(),
+            Err(e) if e.kind() != ErrorKind::InvalidData => {
+                log_warn!(
+                    "Failed to read settings file {} ({e}), using defaults for this session",
+                    file.display()
+                );
+                AppSettings::default()
+            }
+            Err(e) => {
+                let backup = file.with_extension(format!("json.corrupt-{}", time::unix_now()));
+                log_warn!(
+                    "Settings file {} is corrupt ({e}), moving it to {} and using defaults",
+                    file.display(),
+                    backup.display()
+                );
+                let _ = fs::rename(&file, &backup);
+                let settings = AppSettings::default();
+                if let Err(e) = json_file::write_json(&file, &settings) {
+                    log_error!("Failed to write default settings: {e}");
+                }
+                settings
+            }
+        };
+
+        Self {
+            file,
+            settings: Mutex::new(settings),
+        }
+    }
+
+    /// Returns a snapshot of the current settings
+    /// 返回当前设置的快照
+    pub fn get(&self) -> AppSettings {
+        self.lock().clone()
+    }
+
+    /// Merges the fields of `partial` into the settings, validates and persists them
+    /// 将 `partial` 中的字段合并到设置中，验证后持久化
+    ///
+    /// # Arguments
+    /// * `partial` - JSON object containing only the fields to change
+    ///   仅包含需要修改字段的 JSON 对象
+    pub fn update(&self, partial: Value) -> Result<AppSettings, AppError> {
+        let Value::Object(changes) = partial else {
+            return Err(AppError::InvalidSetting {
+                key: String::new(),
+                message: "expected an object of setting fields".to_string(),
+            });
+        };
+
+        let mut settings = self.lock();
+        let mut merged = match serde_json::to_value(&*settings) {
+            Ok(Value::Object(map)) => map,
+            _ => unreachable!("AppSettings always serializes to an object"),
+        };
+
+        for (key, value) in changes {
+            if !merged.contains_key(&key) {
+                return Err(AppError::InvalidSetting {
+                    key,
+                    message: "unknown setting".to_string(),
+                });
+            }
+            merged.insert(key, value);
+        }
+
+        let updated: AppSettings = serde_json::from_value(Value::Object(merged)).map_err(|e| {
+            AppError::InvalidSetting {
+                key: String::new(),
+                message: e.to_string(),
+            }
+        })?;
+        validate(&updated)?;
+
+        json_file::write_json(&self.file, &updated).map_err(|e| AppError::io(&self.file, e))?;
+        logger::set_level(updated.log_level);
+        *settings = updated.clone();
+        Ok(updated)
+    }
+
+    /// Applies a change made by the backend itself and persists it
+    /// 应用由后端自身做出的修改并持久化
+    ///
+    /// The change only takes effect in memory once it has been written.
+    /// 修改写入成功后才会在内存中生效。
+    pub fn update_with(&self, change: impl FnOnce(&mut AppSettings)) -> Result<(), AppError> {
+        let mut settings = self.lock();
+        let mut updated = settings.clone();
+        change(&mut updated);
+        json_file::write_json(&self.file, &updated).map_err(|e| AppError::io(&self.file, e))?;
+        *settings = updated;
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, AppSettings> {
+        self.settings
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Checks value ranges that serde cannot express
+/// 检查 serde 无法表达的取值范围
+fn validate(settings: &AppSettings) -> Result<(), AppError> {
+    if settings.compression_level > 9 {
+        return Err(AppError::InvalidSetting {
+            key: "compression_level".to_string(),
+            message: "must be between 0 and 9".to_string(),
+        });
+    }
+
+    if !archive_format::is_creatable_format(&settings.default_archive_type) {
+        return Err(AppError::InvalidSetting {
+            key: "default_archive_type".to_string(),
+            message: format!("unsupported archive type {}", settings.default_archive_type),
+        });
+    }
+
+    if let Some(dir) = &settings.default_extract_dir
+        && !Path::new(dir).is_absolute()
+    {
+        return Err(AppError::InvalidSetting {
+            key: "default_extract_dir".to_string(),
+            message: "must be an absolute path".to_string(),
+        });
+    }
+
+    if let Some(dir) = &settings.temp_dir_override
+        && !Path::new(dir).is_absolute()
+    {
+        return Err(AppError::InvalidSetting {
+            key: "temp_dir_override".to_string(),
+            message: "must be an absolute path".to_string(),
+        });
+    }
+
+    for (name, profile) in &settings.compression_profiles {
+        if profiles::is_built_in(name) {
+            return Err(AppError::InvalidSetting {
+                key: format!("compression_profiles.{name}"),
+                message: "name is taken by a built-in profile".to_string(),
+            });
+        }
+        profiles::check_profile(profile).map_err(|e| match e {
+            AppError::InvalidArgument {
+                name: field,
+                message,
+            } => AppError::InvalidSetting {
+                key: format!("compression_profiles.{name}.{field}"),
+                message,
+            },
+            other => other,
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty folder for one test, holding the settings file and its backups
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("soarzip-settings-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn as_json(settings: &AppSettings) -> Value {
+        serde_json::to_value(settings).unwrap()
+    }
+
+    fn backups(dir: &Path) -> usize {
+        fs::read_dir(dir)
+            .unwrap()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().contains(".corrupt-"))
+            .count()
+    }
+
+    #[test]
+    fn corrupt_file_is_moved_aside() {
+        let dir = test_dir("corrupt");
+        let file = dir.join(SETTINGS_FILE_NAME);
+        fs::write(&file, b"{ not json").unwrap();
+
+        let store = SettingsStore::load(file.clone());
+        assert_eq!(as_json(&store.get()), as_json(&AppSettings::default()));
+        assert_eq!(backups(&dir), 1);
+        assert!(
+            json_file::read_json::<AppSettings>(&file)
+                .unwrap()
+                .is_some()
+        );
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unreadable_file_is_left_alone() {
+        let dir = test_dir("unreadable");
+        // Reading a folder fails with an I/O error that isn't a parse failure
+        let file = dir.join(SETTINGS_FILE_NAME);
+        fs::create_dir(&file).unwrap();
+
+        let store = SettingsStore::load(file.clone());
+        assert_eq!(as_json(&store.get()), as_json(&AppSettings::default()));
+        assert_eq!(backups(&dir), 0);
+        assert!(file.is_dir());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,92 @@
+//! Taskbar progress service
+//! 任务栏进度服务
+//!
+//! Mirrors operation progress on the main window's taskbar button (dock icon
+//! on macOS). Concurrent operations show their average percentage. Failures
+//! to update the button are ignored, since it is purely cosmetic.
+//! 在主窗口的任务栏按钮（macOS 上为程序坞图标）上同步显示操作进度。并发操作显示其平均百分比。
+//! 更新按钮失败时会被忽略，因为它仅用于展示。
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use tauri::window::{ProgressBarState, ProgressBarStatus};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::models::history::OperationOutcome;
+
+/// How long a warning or failure state stays on the button before it is cleared
+/// 警告或失败状态在按钮上保留多久后被清除
+const OUTCOME_DISPLAY_TIME: Duration = Duration::from_secs(3);
+
+/// Managed state holding the percentage of every running operation
+/// 保存每个运行中操作百分比的托管状态
+#[derive(Default)]
+pub struct TaskbarProgress {
+    running: Mutex<HashMap<String, f64>>,
+}
+
+impl TaskbarProgress {
+    /// Records the percentage of `operation_id` and shows the aggregate
+    /// 记录 `operation_id` 的百分比并显示汇总进度
+    pub fn update<R: Runtime>(&self, app: &AppHandle<R>, operation_id: &str, percent: f64) {
+        let mut running = self.lock();
+        running.insert(operation_id.to_string(), percent.clamp(0.0, 100.0));
+        let average = running.values().sum::<f64>() / running.len() as f64;
+        set_state(app, ProgressBarStatus::Normal, Some(average.round() as u64));
+    }
+
+    /// Removes `operation_id`; the button is cleared once nothing is running
+    /// 移除 `operation_id`；没有运行中的操作时清除按钮状态
+    ///
+    /// A warning or failure is shown briefly as paused or error before clearing.
+    /// 警告或失败会在清除前短暂显示为暂停或错误状态。
+    pub fn finish<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        operation_id: &str,
+        outcome: OperationOutcome,
+    ) {
+        let mut running = self.lock();
+        running.remove(operation_id);
+        if !running.is_empty() {
+            let average = running.values().sum::<f64>() / running.len() as f64;
+            set_state(app, ProgressBarStatus::Normal, Some(average.round() as u64));
+            return;
+        }
+
+        let status = match outcome {
+            OperationOutcome::Success => {
+                set_state(app, ProgressBarStatus::None, None);
+                return;
+            }
+            OperationOutcome::Warning => ProgressBarStatus::Paused,
+            OperationOutcome::Failure => ProgressBarStatus::Error,
+        };
+        set_state(app, status, Some(100));
+
+        let app = app.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(OUTCOME_DISPLAY_TIME);
+            if app.state::<TaskbarProgress>().lock().is_empty() {
+                set_state(&app, ProgressBarStatus::None, None);
+            }
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, f64>> {
+        self.running
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn set_state<R: Runtime>(app: &AppHandle<R>, status: ProgressBarStatus, progress: Option<u64>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.set_progress_bar(ProgressBarState {
+            status: Some(status),
+            progress,
+        });
+    }
+}
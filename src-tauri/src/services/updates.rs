@@ -0,0 +1,185 @@
+//! Update check service
+//! 更新检查服务
+//!
+//! Queries the GitHub releases API and caches the answer for a few hours so
+//! repeated checks don't hit the rate limit.
+//! 查询 GitHub 发布 API 并将结果缓存几个小时，避免重复检查触发速率限制。
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use semver::Version;
+use serde::Deserialize;
+
+use crate::models::error::AppError;
+use crate::models::updates::UpdateInfo;
+use crate::utils::logger::{log_debug, log_warn};
+
+/// Releases endpoint of this repository
+/// 本仓库的发布列表接口
+const RELEASES_URL: &str = "https://api.github.com/repos/ArtaphyLabs/SoarZip/releases?per_page=20";
+
+/// How long a successful check is reused
+/// 成功检查结果的复用时长
+const CACHE_TTL: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// Overall request timeout, kept short so offline machines fail fast
+/// 整体请求超时，设置较短以便离线时快速失败
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    #[serde(default)]
+    body: Option<String>,
+    html_url: String,
+    draft: bool,
+    prerelease: bool,
+    #[serde(default)]
+    assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+struct CachedCheck {
+    checked_at: Instant,
+    include_prereleases: bool,
+    info: UpdateInfo,
+}
+
+/// Managed state caching the last update check
+/// 缓存上次更新检查结果的托管状态
+#[derive(Default)]
+pub struct UpdateCache {
+    last: Mutex<Option<CachedCheck>>,
+}
+
+impl UpdateCache {
+    fn get(&self, include_prereleases: bool) -> Option<UpdateInfo> {
+        let last = self
+            .last
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        last.as_ref()
+            .filter(|cached| {
+                cached.include_prereleases == include_prereleases
+                    && cached.checked_at.elapsed() < CACHE_TTL
+            })
+            .map(|cached| cached.info.clone())
+    }
+
+    fn store(&self, include_prereleases: bool, info: UpdateInfo) {
+        *self
+            .last
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(CachedCheck {
+            checked_at: Instant::now(),
+            include_prereleases,
+            info,
+        });
+    }
+}
+
+/// Returns the newest release compared with the running version, using the cache when fresh
+/// 返回与当前运行版本比较后的最新发布版本，缓存未过期时直接使用缓存
+///
+/// Blocks on network I/O; call it from a blocking task.
+/// 会阻塞于网络 I/O；请在阻塞任务中调用。
+///
+/// # Arguments
+/// * `include_prereleases` - Also consider releases marked as pre-release
+///   同时考虑标记为预发布的版本
+pub fn check(cache: &UpdateCache, include_prereleases: bool) -> Result<UpdateInfo, AppError> {
+    if let Some(info) = cache.get(include_prereleases) {
+        log_debug!("Using cached update check result");
+        return Ok(info);
+    }
+
+    let info = fetch_latest(include_prereleases)?;
+    cache.store(include_prereleases, info.clone());
+    Ok(info)
+}
+
+fn fetch_latest(include_prereleases: bool) -> Result<UpdateInfo, AppError> {
+    let current = Version::parse(env!("CARGO_PKG_VERSION")).expect("package version is semver");
+
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(REQUEST_TIMEOUT))
+        .build()
+        .into();
+    let releases: Vec<Release> = agent
+        .get(RELEASES_URL)
+        .header("User-Agent", concat!("SoarZip/", env!("CARGO_PKG_VERSION")))
+        .header("Accept", "application/vnd.github+json")
+        .call()
+        .and_then(|mut response| response.body_mut().read_json())
+        .map_err(|e| {
+            log_warn!("Update check failed: {e}");
+            AppError::Network {
+                message: e.to_string(),
+            }
+        })?;
+
+    let latest = releases
+        .into_iter()
+        .filter(|release| !release.draft && (include_prereleases || !release.prerelease))
+        .filter_map(|release| {
+            let version = Version::parse(release.tag_name.trim_start_matches('v')).ok()?;
+            (include_prereleases || version.pre.is_empty()).then_some((version, release))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b));
+
+    let Some((version, release)) = latest else {
+        return Ok(UpdateInfo {
+            current_version: current.to_string(),
+            latest_version: current.to_string(),
+            update_available: false,
+            release_notes: String::new(),
+            download_url: String::new(),
+        });
+    };
+
+    Ok(UpdateInfo {
+        current_version: current.to_string(),
+        latest_version: version.to_string(),
+        update_available: version > current,
+        download_url: platform_asset(&release.assets)
+            .map(|asset| asset.browser_download_url.clone())
+            .unwrap_or_else(|| release.html_url.clone()),
+        release_notes: release.body.unwrap_or_default(),
+    })
+}
+
+/// Picks the installer matching the current OS and CPU architecture
+/// 选择与当前操作系统和 CPU 架构匹配的安装包
+fn platform_asset(assets: &[ReleaseAsset]) -> Option<&ReleaseAsset> {
+    let extensions: &[&str] = match std::env::consts::OS {
+        "windows" => &[".msi", "-setup.exe", ".exe"],
+        "macos" => &[".dmg"],
+        _ => &[".appimage", ".deb", ".rpm"],
+    };
+    let arch_markers: &[&str] = match std::env::consts::ARCH {
+        "x86_64" => &["x64", "x86_64", "amd64"],
+        "aarch64" => &["aarch64", "arm64"],
+        _ => &[],
+    };
+
+    extensions.iter().find_map(|extension| {
+        let candidates: Vec<&ReleaseAsset> = assets
+            .iter()
+            .filter(|asset| asset.name.to_ascii_lowercase().ends_with(extension))
+            .collect();
+        candidates
+            .iter()
+            .find(|asset| {
+                let name = asset.name.to_ascii_lowercase();
+                arch_markers.iter().any(|marker| name.contains(marker))
+            })
+            .or(candidates.first())
+            .copied()
+    })
+}
@@ -0,0 +1,273 @@
+//! Multi-volume split helpers
+//! 分卷拆分辅助功能
+//!
+//! Maps media-oriented presets to exact volume sizes and previews how many
+//! volumes a set of sources will produce.
+//! 将面向存储介质的预设映射为精确的分卷大小，并预览一组源文件将产生多少个分卷。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::error::AppError;
+use crate::models::volumes::{VolumeEstimate, VolumeSizePreset};
+
+/// One mebibyte, the unit 7-Zip's `-v<N>m` switch uses
+/// 一 MiB，即 7-Zip `-v<N>m` 开关使用的单位
+const MIB: u64 = 1024 * 1024;
+
+/// Name of the preset that takes an explicit size
+/// 需要显式指定大小的预设名称
+pub const CUSTOM_PRESET: &str = "custom";
+
+/// Fixed archive overhead assumed on top of the sources: signature, end header, padding
+/// 在源文件之外假定的固定压缩包开销：签名、结束头和填充
+const ARCHIVE_OVERHEAD_BYTES: u64 = 4096;
+
+/// Header bytes assumed per file or folder, enough for a zip local and central entry with a long name
+/// 每个文件或文件夹假定的头部字节数，足以容纳带长名称的 zip 本地条目和中央目录条目
+const ENTRY_OVERHEAD_BYTES: u64 = 256;
+
+/// Named volume sizes
+/// 命名的分卷大小
+///
+/// The mail preset leaves room for base64 (4/3) and MIME line breaks under a
+/// 25 MB attachment limit; FAT32 stays just under its 4 GiB file size limit.
+/// 邮件预设在 25 MB 附件上限之下为 base64（4/3）和 MIME 换行留出余量；
+/// FAT32 略小于其 4 GiB 的文件大小上限。
+const VOLUME_PRESETS: &[(&str, u64)] = &[
+    ("mail_18mb", 18_000_000),
+    ("cd_650mb", 650 * MIB),
+    ("cd_700mb", 700 * MIB),
+    ("fat32", 4092 * MIB),
+];
+
+/// Returns the built-in presets followed by "custom"
+/// 返回内置预设，最后是 "custom"
+pub fn presets() -> Vec<VolumeSizePreset> {
+    VOLUME_PRESETS
+        .iter()
+        .map(|(name, bytes)| VolumeSizePreset {
+            name: name.to_string(),
+            bytes: Some(*bytes),
+        })
+        .chain([VolumeSizePreset {
+            name: CUSTOM_PRESET.to_string(),
+            bytes: None,
+        }])
+        .collect()
+}
+
+/// Resolves a preset name, or "custom" with `custom_bytes`, to a volume size in bytes
+/// 将预设名称（或 "custom" 加 `custom_bytes`）解析为以字节为单位的分卷大小
+pub fn volume_bytes(preset: &str, custom_bytes: Option<u64>) -> Result<u64, AppError> {
+    let bytes = if preset == CUSTOM_PRESET {
+        custom_bytes
+    } else {
+        VOLUME_PRESETS
+            .iter()
+            .find(|(name, _)| *name == preset)
+            .map(|(_, bytes)| *bytes)
+    };
+
+    match bytes {
+        Some(bytes) if bytes > 0 => Ok(bytes),
+        Some(_) => Err(AppError::InvalidArgument {
+            name: "custom_bytes".to_string(),
+            message: "volume size must be greater than zero".to_string(),
+        }),
+        None if preset == CUSTOM_PRESET => Err(AppError::InvalidArgument {
+            name: "custom_bytes".to_string(),
+            message: "required for the custom preset".to_string(),
+        }),
+        None => Err(AppError::InvalidArgument {
+            name: "preset".to_string(),
+            message: format!("unknown volume size preset {preset}"),
+        }),
+    }
+}
+
+/// Estimates how many volumes splitting `paths` at `volume_bytes` will produce
+/// 估计以 `volume_bytes` 拆分 `paths` 将产生多少个分卷
+///
+/// `volume_count` is an upper bound that assumes nothing compresses (media,
+/// already-compressed files), so the preview never promises fewer volumes than
+/// the real run creates. `best_case_volume_count` assumes typical compressible
+/// data at `level`.
+/// `volume_count` 是假定数据完全无法压缩（媒体文件、已压缩的文件）时的上限，
+/// 因此预览结果不会少于实际产生的分卷数。`best_case_volume_count` 假定数据在 `level`
+/// 级别下具有典型的可压缩性。
+///
+/// # Arguments
+/// * `paths` - Files and folders that will be archived
+///   将被压缩的文件和文件夹
+/// * `volume_bytes` - Size of each volume
+///   每个分卷的大小
+/// * `level` - Compression level 0-9
+///   压缩级别 0-9
+pub fn estimate(
+    paths: &[PathBuf],
+    volume_bytes: u64,
+    level: u8,
+) -> Result<VolumeEstimate, AppError> {
+    if level > 9 {
+        return Err(AppError::InvalidArgument {
+            name: "level".to_string(),
+            message: "must be between 0 and 9".to_string(),
+        });
+    }
+    if volume_bytes == 0 {
+        return Err(AppError::InvalidArgument {
+            name: "volume_bytes".to_string(),
+            message: "volume size must be greater than zero".to_string(),
+        });
+    }
+
+    let mut sources = SourceSize::default();
+    for path in paths {
+        if !path.exists() {
+            return Err(AppError::NotFound {
+                path: path.display().to_string(),
+            });
+        }
+        add_size(path, &mut sources).map_err(|e| AppError::io(path, e))?;
+    }
+
+    Ok(estimate_sources(&sources, volume_bytes, level))
+}
+
+/// Builds the estimate for measured sources; sizes saturate instead of overflowing
+/// 根据已测量的源文件生成估计结果；大小达到上限时饱和而不会溢出
+fn estimate_sources(sources: &SourceSize, volume_bytes: u64, level: u8) -> VolumeEstimate {
+    let overhead_bytes =
+        ARCHIVE_OVERHEAD_BYTES.saturating_add(sources.entries.saturating_mul(ENTRY_OVERHEAD_BYTES));
+    let estimated_archive_bytes = sources.bytes.saturating_add(overhead_bytes);
+    // Float to integer casts saturate, so huge sources can't wrap around here
+    let best_case_archive_bytes = ((sources.bytes as f64 * typical_ratio(level)).ceil() as u64)
+        .saturating_add(overhead_bytes);
+    VolumeEstimate {
+        source_bytes: sources.bytes,
+        estimated_archive_bytes,
+        volume_bytes,
+        volume_count: volume_count(estimated_archive_bytes, volume_bytes),
+        best_case_volume_count: volume_count(best_case_archive_bytes, volume_bytes),
+    }
+}
+
+/// Number of `volume_bytes` volumes needed for `archive_bytes`, at least one
+/// 容纳 `archive_bytes` 所需的 `volume_bytes` 分卷数，至少为一个
+fn volume_count(archive_bytes: u64, volume_bytes: u64) -> u64 {
+    archive_bytes.div_ceil(volume_bytes).max(1)
+}
+
+/// Compressed/original size ratio of typical compressible data at a compression level
+/// 典型可压缩数据在某压缩级别下的压缩后/原始大小比率
+fn typical_ratio(level: u8) -> f64 {
+    match level {
+        0 => 1.0,
+        1..=3 => 0.9,
+        4..=6 => 0.85,
+        _ => 0.8,
+    }
+}
+
+/// Bytes and entry count of the sources
+/// 源文件的字节数和条目数
+#[derive(Default)]
+struct SourceSize {
+    bytes: u64,
+    entries: u64,
+}
+
+/// Adds the files and folders under `path` to `size` without following symlinks
+/// 将 `path` 下的文件和文件夹计入 `size`，不跟随符号链接
+fn add_size(path: &Path, size: &mut SourceSize) -> std::io::Result<()> {
+    let metadata = fs::symlink_metadata(path)?;
+    size.entries += 1;
+    if !metadata.is_dir() {
+        size.bytes = size.bytes.saturating_add(metadata.len());
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(path)? {
+        add_size(&entry?.path(), size)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sources whose uncompressed upper-bound archive is exactly `archive_bytes`
+    fn sources_for(archive_bytes: u64) -> SourceSize {
+        SourceSize {
+            bytes: archive_bytes - ARCHIVE_OVERHEAD_BYTES - ENTRY_OVERHEAD_BYTES,
+            entries: 1,
+        }
+    }
+
+    #[test]
+    fn exact_multiple_needs_no_extra_volume() {
+        let estimate = estimate_sources(&sources_for(3 * MIB), MIB, 0);
+        assert_eq!(estimate.estimated_archive_bytes, 3 * MIB);
+        assert_eq!(estimate.volume_count, 3);
+
+        let one_more = estimate_sources(&sources_for(3 * MIB + 1), MIB, 0);
+        assert_eq!(one_more.volume_count, 4);
+    }
+
+    #[test]
+    fn empty_sources_still_need_one_volume() {
+        let estimate = estimate_sources(&SourceSize::default(), MIB, 9);
+        assert_eq!(estimate.source_bytes, 0);
+        assert_eq!(estimate.estimated_archive_bytes, ARCHIVE_OVERHEAD_BYTES);
+        assert_eq!(estimate.volume_count, 1);
+        assert_eq!(estimate.best_case_volume_count, 1);
+        assert_eq!(volume_count(0, MIB), 1);
+    }
+
+    #[test]
+    fn volume_larger_than_archive_gives_one_volume() {
+        let estimate = estimate_sources(&sources_for(MIB), 4092 * MIB, 5);
+        assert_eq!(estimate.volume_count, 1);
+        assert_eq!(estimate.best_case_volume_count, 1);
+    }
+
+    #[test]
+    fn huge_sources_saturate_instead_of_overflowing() {
+        let sources = SourceSize {
+            bytes: u64::MAX - 10,
+            entries: u64::MAX,
+        };
+        let estimate = estimate_sources(&sources, 1, 0);
+        assert_eq!(estimate.estimated_archive_bytes, u64::MAX);
+        assert_eq!(estimate.volume_count, u64::MAX);
+        assert_eq!(estimate.best_case_volume_count, u64::MAX);
+        assert_eq!(volume_count(u64::MAX, u64::MAX), 1);
+    }
+
+    #[test]
+    fn best_case_is_never_above_the_upper_bound() {
+        let sources = sources_for(100 * MIB);
+        for level in 0..=9 {
+            let estimate = estimate_sources(&sources, MIB, level);
+            assert!(estimate.best_case_volume_count <= estimate.volume_count);
+        }
+    }
+
+    #[test]
+    fn zero_sized_volumes_are_rejected() {
+        assert!(volume_bytes(CUSTOM_PRESET, Some(0)).is_err());
+        assert!(volume_bytes(CUSTOM_PRESET, None).is_err());
+        assert!(estimate(&[], 0, 5).is_err());
+    }
+
+    #[test]
+    fn presets_resolve_to_their_sizes() {
+        assert_eq!(volume_bytes("mail_18mb", None).unwrap(), 18_000_000);
+        assert_eq!(volume_bytes("fat32", Some(1)).unwrap(), 4092 * MIB);
+        assert_eq!(volume_bytes(CUSTOM_PRESET, Some(5)).unwrap(), 5);
+        assert!(volume_bytes("floppy", None).is_err());
+        assert_eq!(presets().last().unwrap().name, CUSTOM_PRESET);
+    }
+}
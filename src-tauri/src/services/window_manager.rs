@@ -0,0 +1,101 @@
+//! Secondary window management service
+//! 附加窗口管理服务
+//!
+//! Opens extra windows so archives can be compared side by side. Each window
+//! gets a unique label and its initial archive path is kept in managed state
+//! until that window's frontend asks for it.
+//! 打开额外的窗口以便并排比较压缩包。每个窗口都有唯一标签，其初始压缩包路径保存在
+//! 托管状态中，直到该窗口的前端请求获取。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use tauri::{AppHandle, Runtime, WebviewUrl, WebviewWindowBuilder};
+
+use crate::models::error::AppError;
+use crate::utils::logger::log_error;
+
+/// Label prefix of windows opened through `open_window`
+/// 通过 `open_window` 打开的窗口的标签前缀
+pub const ARCHIVE_WINDOW_PREFIX: &str = "archive-";
+
+/// Managed state mapping window labels to the archive they should open
+/// 将窗口标签映射到其应打开的压缩包的托管状态
+#[derive(Default)]
+pub struct WindowArchives {
+    next_id: AtomicU32,
+    pending: Mutex<HashMap<String, String>>,
+}
+
+impl WindowArchives {
+    /// Removes and returns the archive path waiting for `label`
+    /// 移除并返回等待 `label` 窗口的压缩包路径
+    pub fn take(&self, label: &str) -> Option<String> {
+        self.pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .remove(label)
+    }
+
+    /// Number of windows whose archive path has not been taken yet
+    /// 压缩包路径尚未被取走的窗口数
+    pub fn pending_count(&self) -> usize {
+        self.pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .len()
+    }
+
+    fn insert(&self, label: String, archive_path: String) {
+        self.pending
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(label, archive_path);
+    }
+}
+
+/// Opens a new application window, optionally with an archive to load
+/// 打开新的应用程序窗口，可选地指定要加载的压缩包
+///
+/// # Arguments
+/// * `archive_path` - Archive the new window should open on startup
+///   新窗口启动时应打开的压缩包
+///
+/// # Returns
+/// The label of the created window
+/// 新创建窗口的标签
+pub fn open_window<R: Runtime>(
+    app: &AppHandle<R>,
+    windows: &WindowArchives,
+    archive_path: Option<String>,
+) -> Result<String, AppError> {
+    let label = format!(
+        "{ARCHIVE_WINDOW_PREFIX}{}",
+        windows.next_id.fetch_add(1, Ordering::Relaxed)
+    );
+
+    let title = archive_path
+        .as_deref()
+        .and_then(|path| std::path::Path::new(path).file_name())
+        .map(|name| format!("{} - soar-zip", name.to_string_lossy()))
+        .unwrap_or_else(|| "soar-zip".to_string());
+
+    if let Some(path) = archive_path {
+        windows.insert(label.clone(), path);
+    }
+
+    WebviewWindowBuilder::new(app, &label, WebviewUrl::default())
+        .title(title)
+        .inner_size(800.0, 600.0)
+        .build()
+        .map_err(|e| {
+            log_error!("Failed to create window {label}: {e}");
+            windows.take(&label);
+            AppError::WindowCreation {
+                message: e.to_string(),
+            }
+        })?;
+
+    Ok(label)
+}
@@ -0,0 +1,153 @@
+//! Window geometry persistence service
+//! 窗口几何信息持久化服务
+//!
+//! Saves the main window's size, position and maximized state on close and
+//! applies them at startup while the window is still hidden.
+//! 在关闭时保存主窗口的大小、位置和最大化状态，并在启动时趁窗口仍隐藏时应用它们。
+
+use tauri::{
+    AppHandle, Manager, Monitor, PhysicalPosition, PhysicalSize, Runtime, WebviewWindow, Window,
+};
+
+use crate::models::settings::WindowGeometry;
+use crate::services::settings::SettingsStore;
+use crate::utils::logger::log_warn;
+
+/// Height, in pixels, of the title bar strip that must stay on screen
+/// 必须保持在屏幕内的标题栏区域高度（像素）
+const TITLE_BAR_PIXELS: i64 = 40;
+
+/// Applies the saved geometry to `window`, then shows it
+/// 将保存的几何信息应用到 `window`，然后显示窗口
+///
+/// The window is configured hidden so a maximized window never flashes at its
+/// default size first. It is always shown, even if restoring fails.
+/// 窗口被配置为隐藏启动，因此最大化窗口不会先以默认大小闪现。即使恢复失败也始终会显示窗口。
+pub fn restore<R: Runtime>(window: &WebviewWindow<R>, settings: &SettingsStore) {
+    if let Some(geometry) = settings.get().window_state {
+        apply(window, &geometry);
+    }
+    let _ = window.show();
+}
+
+/// Records the current geometry of `window` into the settings
+/// 将 `window` 当前的几何信息记录到设置中
+///
+/// While maximized or minimized the OS reports bounds that aren't the ones
+/// to restore, so only the flag changes and the last normal bounds are kept.
+/// 最大化或最小化时系统报告的边界并非需要恢复的边界，因此只更新标志并保留上次的正常边界。
+pub fn save<R: Runtime>(window: &Window<R>, settings: &SettingsStore) {
+    let maximized = window.is_maximized().unwrap_or(false);
+    let minimized = window.is_minimized().unwrap_or(false);
+    let previous = settings.get().window_state;
+
+    let geometry = match (previous, maximized || minimized) {
+        (Some(previous), true) => WindowGeometry {
+            maximized,
+            ..previous
+        },
+        _ => {
+            let (Ok(position), Ok(size)) = (window.outer_position(), window.inner_size()) else {
+                return;
+            };
+            WindowGeometry {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized,
+            }
+        }
+    };
+
+    if let Err(e) = settings.update_with(|settings| settings.window_state = Some(geometry)) {
+        log_warn!("Failed to save window geometry: {e}");
+    }
+}
+
+/// Records the main window's geometry when the app exits without closing it first
+/// 应用未先关闭主窗口就退出时记录主窗口的几何信息
+///
+/// Covers quitting from the tray or the OS shutting down; does nothing before
+/// the settings are loaded.
+/// 涵盖从托盘退出或操作系统关机的情况；设置加载完成之前不执行任何操作。
+pub fn save_main<R: Runtime>(app: &AppHandle<R>) {
+    if let (Some(window), Some(settings)) = (
+        app.get_webview_window("main"),
+        app.try_state::<SettingsStore>(),
+    ) {
+        save(&window.as_ref().window(), &settings);
+    }
+}
+
+fn apply<R: Runtime>(window: &WebviewWindow<R>, geometry: &WindowGeometry) {
+    let monitors = window.available_monitors().unwrap_or_default();
+    let target = monitors
+        .iter()
+        .map(|monitor| (monitor, overlap_area(monitor, geometry)))
+        .filter(|(_, area)| *area > 0)
+        .max_by_key(|(_, area)| *area);
+    match target {
+        Some((monitor, _)) => {
+            let width = geometry.width.min(monitor.size().width);
+            let height = geometry.height.min(monitor.size().height);
+            let (x, y) = clamp_to_monitor(monitor, geometry.x, geometry.y, width);
+            let _ = window.set_size(PhysicalSize::new(width, height));
+            let _ = window.set_position(PhysicalPosition::new(x, y));
+        }
+        // Saved on a monitor that is no longer connected: keep the size, recenter
+        None => {
+            let _ = window.set_size(PhysicalSize::new(geometry.width, geometry.height));
+            let _ = window.center();
+        }
+    }
+
+    if geometry.maximized {
+        let _ = window.maximize();
+    }
+}
+
+/// Area, in square pixels, of the saved rectangle that lies on `monitor`
+/// 保存的矩形位于 `monitor` 上的面积（平方像素）
+fn overlap_area(monitor: &Monitor, geometry: &WindowGeometry) -> i64 {
+    let (left, top, right, bottom) = bounds(monitor);
+
+    let overlap_x = right.min(i64::from(geometry.x) + i64::from(geometry.width))
+        - left.max(i64::from(geometry.x));
+    let overlap_y = bottom.min(i64::from(geometry.y) + i64::from(geometry.height))
+        - top.max(i64::from(geometry.y));
+
+    overlap_x.max(0) * overlap_y.max(0)
+}
+
+/// Moves the top-left corner so the whole title bar of a `width`-wide window lies on `monitor`
+/// 移动左上角，使宽度为 `width` 的窗口的整个标题栏位于 `monitor` 上
+///
+/// `width` must already fit the monitor.
+/// `width` 必须已经不超过显示器宽度。
+fn clamp_to_monitor(monitor: &Monitor, x: i32, y: i32, width: u32) -> (i32, i32) {
+    let (left, top, right, bottom) = bounds(monitor);
+    let x = i64::from(x).clamp(left, right - i64::from(width));
+    let y = i64::from(y).clamp(top, (bottom - TITLE_BAR_PIXELS).max(top));
+    (
+        i32::try_from(x).unwrap_or(i32::MAX),
+        i32::try_from(y).unwrap_or(i32::MAX),
+    )
+}
+
+/// Left, top, right and bottom edges of `monitor` in physical pixels
+/// `monitor` 的左、上、右、下边界（物理像素）
+///
+/// The locked tauri 2.5 doesn't expose the monitor work area yet, so these
+/// are the full monitor bounds, taskbar or dock included.
+/// 当前锁定的 tauri 2.5 尚未提供显示器工作区，因此这里是包含任务栏或程序坞的完整显示器边界。
+fn bounds(monitor: &Monitor) -> (i64, i64, i64, i64) {
+    let left = i64::from(monitor.position().x);
+    let top = i64::from(monitor.position().y);
+    (
+        left,
+        top,
+        left + i64::from(monitor.size().width),
+        top + i64::from(monitor.size().height),
+    )
+}
@@ -0,0 +1,191 @@
+//! Archive format detection by magic bytes and file extension
+//! 基于魔数和文件扩展名的压缩格式检测
+//!
+//! Used to check that a path handed to the app really is an archive before
+//! asking the frontend to open it. `SUPPORTED_EXTENSIONS` is the single list
+//! shared by drag-drop, command line validation and the frontend's file dialogs.
+//! 在请求前端打开某个路径之前，用于检查该路径确实是压缩包。`SUPPORTED_EXTENSIONS`
+//! 是拖放、命令行校验和前端文件对话框共用的唯一列表。
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Signatures found at a fixed offset from the start of the file
+/// 位于文件开头固定偏移处的签名
+const SIGNATURES: &[(&str, usize, &[u8])] = &[
+    ("zip", 0, b"PK\x03\x04"),
+    ("zip", 0, b"PK\x05\x06"),
+    ("zip", 0, b"PK\x07\x08"),
+    ("7z", 0, b"7z\xBC\xAF\x27\x1C"),
+    ("rar", 0, b"Rar!\x1A\x07"),
+    ("gzip", 0, b"\x1F\x8B"),
+    ("bzip2", 0, b"BZh"),
+    ("xz", 0, b"\xFD7zXZ\x00"),
+    ("zstd", 0, b"\x28\xB5\x2F\xFD"),
+    ("lz4", 0, b"\x04\x22\x4D\x18"),
+    ("z", 0, b"\x1F\x9D"),
+    ("cab", 0, b"MSCF"),
+    ("msi", 0, b"\xD0\xCF\x11\xE0\xA1\xB1\x1A\xE1"),
+    ("wim", 0, b"MSWIM\x00\x00\x00"),
+    ("rpm", 0, b"\xED\xAB\xEE\xDB"),
+    ("deb", 0, b"!<arch>\ndebian"),
+    ("arj", 0, b"\x60\xEA"),
+    ("lzh", 2, b"-lh"),
+    ("tar", 257, b"ustar"),
+    ("iso", 0x8001, b"CD001"),
+];
+
+/// Extensions 7-Zip can open, lowercase without the dot, with the format they imply
+/// 7-Zip 可以打开的扩展名（小写、不含点）及其对应的格式
+pub const SUPPORTED_EXTENSIONS: &[(&str, &str)] = &[
+    ("7z", "7z"),
+    ("zip", "zip"),
+    ("zipx", "zip"),
+    ("jar", "zip"),
+    ("rar", "rar"),
+    ("tar", "tar"),
+    ("tar.gz", "gzip"),
+    ("tgz", "gzip"),
+    ("gz", "gzip"),
+    ("tar.bz2", "bzip2"),
+    ("tbz2", "bzip2"),
+    ("bz2", "bzip2"),
+    ("tar.xz", "xz"),
+    ("txz", "xz"),
+    ("xz", "xz"),
+    ("tar.zst", "zstd"),
+    ("zst", "zstd"),
+    ("lz4", "lz4"),
+    ("lzma", "lzma"),
+    ("z", "z"),
+    ("cab", "cab"),
+    ("msi", "msi"),
+    ("wim", "wim"),
+    ("iso", "iso"),
+    ("dmg", "dmg"),
+    ("vhd", "vhd"),
+    ("vhdx", "vhdx"),
+    ("rpm", "rpm"),
+    ("deb", "deb"),
+    ("cpio", "cpio"),
+    ("arj", "arj"),
+    ("lzh", "lzh"),
+    ("lha", "lzh"),
+];
+
+/// Formats 7-Zip can create; everything else is browse and extract only
+/// 7-Zip 可以创建的格式；其余格式仅支持浏览和解压
+const CREATABLE_FORMATS: &[&str] = &["7z", "zip", "tar", "gzip", "bzip2", "xz", "wim"];
+
+/// Formats whose existing archives 7-Zip can add to or delete from
+/// 7-Zip 可以向已有压缩包中添加或删除内容的格式
+///
+/// The single-stream gzip, bzip2 and xz can only be written whole;
+/// `7z u` and `7z d` fail on them with E_NOTIMPL.
+/// 单流格式 gzip、bzip2 和 xz 只能整体写入；`7z u` 和 `7z d` 对它们会以 E_NOTIMPL 失败。
+const UPDATABLE_FORMATS: &[&str] = &["7z", "zip", "tar", "wim"];
+
+/// Whether new archives of `format` can be created
+/// 是否可以创建 `format` 格式的新压缩包
+pub fn is_creatable_format(format: &str) -> bool {
+    CREATABLE_FORMATS.contains(&format)
+}
+
+/// Whether existing archives of `format` can be modified
+/// `format` 格式的已有压缩包是否可以被修改
+pub fn is_writable_format(format: &str) -> bool {
+    UPDATABLE_FORMATS.contains(&format)
+}
+
+/// Detects the archive format of `path` from its extension, preferring compound ones like `tar.gz`
+/// 根据 `path` 的扩展名检测压缩格式，优先匹配 `tar.gz` 这样的复合扩展名
+pub fn format_from_extension(path: &Path) -> Option<&'static str> {
+    matching_extension(path).map(|(_, format)| *format)
+}
+
+/// File name of `path` without its archive extension, e.g. `backup` for `backup.tar.gz`
+/// `path` 去掉压缩扩展名后的文件名，例如 `backup.tar.gz` 对应 `backup`
+///
+/// Names without a supported extension lose only their last extension.
+/// 没有受支持扩展名的文件名只去掉最后一个扩展名。
+pub fn archive_stem(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_string_lossy().into_owned();
+    let stem = matching_extension(path)
+        .and_then(|(extension, _)| name.get(..name.len().checked_sub(extension.len() + 1)?))
+        .map(str::to_string)
+        .or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })?;
+    (!stem.is_empty()).then_some(stem)
+}
+
+fn matching_extension(path: &Path) -> Option<&'static (&'static str, &'static str)> {
+    let name = path.file_name()?.to_string_lossy().to_lowercase();
+    SUPPORTED_EXTENSIONS
+        .iter()
+        .filter(|(extension, _)| {
+            name.strip_suffix(extension)
+                .is_some_and(|stem| stem.len() > 1 && stem.ends_with('.'))
+        })
+        .max_by_key(|(extension, _)| extension.len())
+}
+
+/// Detects the archive format of `path` from its content, falling back to its extension
+/// 根据内容检测 `path` 的压缩格式，无法识别时回退到扩展名
+///
+/// The fallback covers formats without a leading signature, such as dmg and vhd.
+/// 回退用于覆盖没有前导签名的格式，例如 dmg 和 vhd。
+pub fn detect_or_guess_format(path: &Path) -> Option<&'static str> {
+    detect_format(path).or_else(|| format_from_extension(path))
+}
+
+/// Detects the archive format of `path` from its leading bytes
+/// 根据文件开头的字节检测 `path` 的压缩格式
+///
+/// # Returns
+/// The format name (e.g. "zip", "7z"), or `None` when unrecognized or unreadable
+/// 格式名称（例如 "zip"、"7z"），无法识别或无法读取时返回 `None`
+pub fn detect_format(path: &Path) -> Option<&'static str> {
+    let header_len = SIGNATURES
+        .iter()
+        .map(|(_, offset, magic)| offset + magic.len())
+        .max()
+        .unwrap_or_default();
+
+    let mut header = Vec::with_capacity(header_len);
+    File::open(path)
+        .ok()?
+        .take(header_len as u64)
+        .read_to_end(&mut header)
+        .ok()?;
+
+    SIGNATURES
+        .iter()
+        .find(|(_, offset, magic)| header.get(*offset..offset + magic.len()) == Some(*magic))
+        .map(|(format, _, _)| *format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_stream_formats_are_creatable_but_not_writable() {
+        for format in ["gzip", "bzip2", "xz"] {
+            assert!(is_creatable_format(format), "{format}");
+            assert!(!is_writable_format(format), "{format}");
+        }
+    }
+
+    #[test]
+    fn container_formats_are_creatable_and_writable() {
+        for format in ["7z", "zip", "tar", "wim"] {
+            assert!(is_creatable_format(format), "{format}");
+            assert!(is_writable_format(format), "{format}");
+        }
+        assert!(!is_creatable_format("rar"));
+        assert!(!is_writable_format("rar"));
+    }
+}
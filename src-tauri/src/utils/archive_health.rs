@@ -0,0 +1,334 @@
+//! Quick structural checks for archives
+//! 压缩包的快速结构检查
+//!
+//! Reads only the header and trailer of a file to catch truncated downloads
+//! without the cost of a full `7z t`.
+//! 只读取文件的头部和尾部，无需完整运行 `7z t` 即可发现不完整的下载。
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::models::formats::ArchiveHealth;
+use crate::utils::archive_format;
+
+/// Signature of the zip end-of-central-directory record
+/// zip 中央目录结束记录的签名
+const ZIP_EOCD_SIGNATURE: &[u8] = b"PK\x05\x06";
+
+/// Size of the end-of-central-directory record without its trailing comment
+/// 不含尾部注释的中央目录结束记录的大小
+const ZIP_EOCD_LEN: usize = 22;
+
+/// Largest trailing comment a zip may carry after the EOCD record
+/// zip 在中央目录结束记录之后可携带的最大尾部注释长度
+const ZIP_MAX_COMMENT_LEN: usize = 0xFFFF;
+
+/// Size of the fixed 7z signature header
+/// 7z 固定签名头的大小
+const SEVEN_ZIP_SIGNATURE_HEADER_LEN: u64 = 32;
+
+/// Checks whether `path` looks complete, reading only its first and last bytes
+/// 仅读取文件开头和结尾的字节，检查 `path` 是否看起来完整
+///
+/// Only zip and 7z are checked structurally; other formats report `Unknown`,
+/// as do parts of a split set, whose records point into other parts.
+/// 只对 zip 和 7z 进行结构检查；其他格式返回 `Unknown`，分卷中的各部分也是如此，
+/// 因为其记录指向其他部分。
+pub fn quick_check(path: &Path) -> io::Result<ArchiveHealth> {
+    if is_split_volume(path) {
+        return Ok(ArchiveHealth::Unknown);
+    }
+    match archive_format::detect_format(path) {
+        Some("zip") => check_zip(path),
+        Some("7z") => check_7z(path),
+        _ => Ok(ArchiveHealth::Unknown),
+    }
+}
+
+/// Looks for the end-of-central-directory record and checks the directory it points to fits
+/// 查找中央目录结束记录，并检查其指向的中央目录是否在文件范围内
+fn check_zip(path: &Path) -> io::Result<ArchiveHealth> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    // The first volume of a spanned zip has no end record of its own
+    let mut head = [0u8; 4];
+    file.read_exact(&mut head)?;
+    if &head == b"PK\x07\x08" {
+        return Ok(ArchiveHealth::Unknown);
+    }
+
+    let tail_len = len.min((ZIP_EOCD_LEN + ZIP_MAX_COMMENT_LEN) as u64);
+    let tail_start = len - tail_len;
+    let mut tail = Vec::with_capacity(tail_len as usize);
+    file.seek(SeekFrom::Start(tail_start))?;
+    file.take(tail_len).read_to_end(&mut tail)?;
+
+    if tail.len() < ZIP_EOCD_LEN {
+        return Ok(ArchiveHealth::Truncated);
+    }
+    let Some(eocd) = (0..=tail.len() - ZIP_EOCD_LEN)
+        .rev()
+        .find(|&at| tail[at..].starts_with(ZIP_EOCD_SIGNATURE))
+    else {
+        return Ok(ArchiveHealth::Truncated);
+    };
+
+    let read_u32 =
+        |at: usize| u32::from_le_bytes([tail[at], tail[at + 1], tail[at + 2], tail[at + 3]]);
+    let directory_size = read_u32(eocd + 12);
+    let directory_offset = read_u32(eocd + 16);
+    if directory_size == u32::MAX || directory_offset == u32::MAX {
+        // Zip64 keeps the real values in another record; the end record is enough here
+        return Ok(ArchiveHealth::Healthy);
+    }
+
+    let directory_end = u64::from(directory_offset) + u64::from(directory_size);
+    if directory_end > tail_start + eocd as u64 {
+        return Ok(ArchiveHealth::Truncated);
+    }
+    Ok(ArchiveHealth::Healthy)
+}
+
+/// Checks that the end header the signature header points to lies inside the file
+/// 检查签名头指向的结束头是否位于文件范围内
+fn check_7z(path: &Path) -> io::Result<ArchiveHealth> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut header = [0u8; SEVEN_ZIP_SIGNATURE_HEADER_LEN as usize];
+    if let Err(e) = file.read_exact(&mut header) {
+        return match e.kind() {
+            io::ErrorKind::UnexpectedEof => Ok(ArchiveHealth::Truncated),
+            _ => Err(e),
+        };
+    }
+
+    let read_u64 = |at: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&header[at..at + 8]);
+        u64::from_le_bytes(bytes)
+    };
+    let next_header_offset = read_u64(12);
+    let next_header_size = read_u64(20);
+
+    // An empty archive is the bare signature header with both fields zero. 7-Zip
+    // also leaves them zero until it finishes writing, so anything after the
+    // header means the write was interrupted.
+    if next_header_offset == 0 && next_header_size == 0 {
+        return Ok(match len {
+            SEVEN_ZIP_SIGNATURE_HEADER_LEN => ArchiveHealth::Healthy,
+            _ => ArchiveHealth::Truncated,
+        });
+    }
+
+    let header_end = SEVEN_ZIP_SIGNATURE_HEADER_LEN
+        .checked_add(next_header_offset)
+        .and_then(|end| end.checked_add(next_header_size));
+    match header_end {
+        Some(end) if end <= len => Ok(ArchiveHealth::Healthy),
+        _ => Ok(ArchiveHealth::Truncated),
+    }
+}
+
+/// Whether `path` is one part of a split archive, named like `backup.7z.001`
+/// `path` 是否为分卷压缩包的一部分，命名形如 `backup.7z.001`
+fn is_split_volume(path: &Path) -> bool {
+    path.extension()
+        .map(|extension| extension.to_string_lossy())
+        .is_some_and(|extension| {
+            extension.len() >= 3 && extension.chars().all(|c| c.is_ascii_digit())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// Archive bytes written to a temp file, removed on drop
+    struct Fixture(PathBuf);
+
+    impl Fixture {
+        fn new(name: &str, bytes: &[u8]) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("soarzip-health-{}-{name}", std::process::id()));
+            fs::write(&path, bytes).unwrap();
+            Self(path)
+        }
+
+        fn check(&self) -> ArchiveHealth {
+            quick_check(&self.0).unwrap()
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    /// A stored zip holding `a.txt`; CRCs and timestamps are not checked
+    fn zip_with_one_file() -> Vec<u8> {
+        let name = b"a.txt";
+        let data = b"hello";
+
+        let mut zip = Vec::new();
+        zip.extend_from_slice(b"PK\x03\x04");
+        zip.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        zip.extend_from_slice(&[0; 4]);
+        zip.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&[0, 0]);
+        zip.extend_from_slice(name);
+        zip.extend_from_slice(data);
+
+        let directory_offset = zip.len() as u32;
+        zip.extend_from_slice(b"PK\x01\x02");
+        zip.extend_from_slice(&[20, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        zip.extend_from_slice(&[0; 4]);
+        zip.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&[0; 12]);
+        zip.extend_from_slice(&0u32.to_le_bytes());
+        zip.extend_from_slice(name);
+        let directory_size = zip.len() as u32 - directory_offset;
+
+        zip.extend_from_slice(ZIP_EOCD_SIGNATURE);
+        zip.extend_from_slice(&[0, 0, 0, 0, 1, 0, 1, 0]);
+        zip.extend_from_slice(&directory_size.to_le_bytes());
+        zip.extend_from_slice(&directory_offset.to_le_bytes());
+        zip.extend_from_slice(&[0, 0]);
+        zip
+    }
+
+    fn empty_zip() -> Vec<u8> {
+        let mut zip = ZIP_EOCD_SIGNATURE.to_vec();
+        zip.extend_from_slice(&[0; ZIP_EOCD_LEN - 4]);
+        zip
+    }
+
+    /// A 7z signature header pointing at an end header of `header_size` bytes after `body_len` bytes
+    fn seven_zip(body_len: u64, header_size: u64, actual_len: usize) -> Vec<u8> {
+        let mut archive = b"7z\xBC\xAF\x27\x1C\x00\x04".to_vec();
+        archive.extend_from_slice(&[0; 4]);
+        archive.extend_from_slice(&body_len.to_le_bytes());
+        archive.extend_from_slice(&header_size.to_le_bytes());
+        archive.extend_from_slice(&[0; 4]);
+        archive.resize(actual_len, 0);
+        archive
+    }
+
+    #[test]
+    fn healthy_zip() {
+        assert_eq!(
+            Fixture::new("healthy.zip", &zip_with_one_file()).check(),
+            ArchiveHealth::Healthy
+        );
+    }
+
+    #[test]
+    fn zip_with_trailing_comment() {
+        let mut zip = zip_with_one_file();
+        let comment = b"made by a test";
+        let len = zip.len();
+        zip[len - 2..].copy_from_slice(&(comment.len() as u16).to_le_bytes());
+        zip.extend_from_slice(comment);
+        assert_eq!(
+            Fixture::new("comment.zip", &zip).check(),
+            ArchiveHealth::Healthy
+        );
+    }
+
+    #[test]
+    fn truncated_zip() {
+        let zip = zip_with_one_file();
+        assert_eq!(
+            Fixture::new("truncated.zip", &zip[..zip.len() / 2]).check(),
+            ArchiveHealth::Truncated
+        );
+        assert_eq!(
+            Fixture::new("cut-end.zip", &zip[..zip.len() - 10]).check(),
+            ArchiveHealth::Truncated
+        );
+    }
+
+    #[test]
+    fn empty_zip_is_healthy() {
+        assert_eq!(
+            Fixture::new("empty.zip", &empty_zip()).check(),
+            ArchiveHealth::Healthy
+        );
+    }
+
+    #[test]
+    fn first_part_of_split_zip_is_unknown() {
+        let zip = zip_with_one_file();
+        assert_eq!(
+            Fixture::new("split.zip.001", &zip[..zip.len() / 2]).check(),
+            ArchiveHealth::Unknown
+        );
+    }
+
+    #[test]
+    fn first_part_of_split_7z_is_unknown() {
+        // The end header lies in a later part, well past this one
+        assert_eq!(
+            Fixture::new("split.7z.001", &seven_zip(10_000, 20, 1000)).check(),
+            ArchiveHealth::Unknown
+        );
+        assert_eq!(
+            Fixture::new("unsplit.7z", &seven_zip(10_000, 20, 1000)).check(),
+            ArchiveHealth::Truncated
+        );
+    }
+
+    #[test]
+    fn healthy_7z() {
+        assert_eq!(
+            Fixture::new("healthy.7z", &seven_zip(100, 20, 152)).check(),
+            ArchiveHealth::Healthy
+        );
+    }
+
+    #[test]
+    fn truncated_7z() {
+        assert_eq!(
+            Fixture::new("truncated.7z", &seven_zip(100, 20, 140)).check(),
+            ArchiveHealth::Truncated
+        );
+        assert_eq!(
+            Fixture::new("short.7z", &seven_zip(0, 0, 32)[..20]).check(),
+            ArchiveHealth::Truncated
+        );
+    }
+
+    #[test]
+    fn empty_7z_is_healthy() {
+        assert_eq!(
+            Fixture::new("empty.7z", &seven_zip(0, 0, 32)).check(),
+            ArchiveHealth::Healthy
+        );
+    }
+
+    #[test]
+    fn unfinished_7z_is_truncated() {
+        assert_eq!(
+            Fixture::new("unfinished.7z", &seven_zip(0, 0, 4096)).check(),
+            ArchiveHealth::Truncated
+        );
+    }
+
+    #[test]
+    fn other_formats_are_unknown() {
+        assert_eq!(
+            Fixture::new("data.gz", b"\x1F\x8B\x08\x00").check(),
+            ArchiveHealth::Unknown
+        );
+    }
+}
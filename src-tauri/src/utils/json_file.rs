@@ -0,0 +1,76 @@
+//! JSON file persistence helpers
+//! JSON 文件持久化辅助函数
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::utils::retry;
+
+/// Reads and parses a JSON file, returning `Ok(None)` when it does not exist
+/// 读取并解析 JSON 文件，文件不存在时返回 `Ok(None)`
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> io::Result<Option<T>> {
+    let content = match fs::read(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    serde_json::from_slice(&content)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Serializes `value` to `path` through a temporary file and a rename
+/// 通过临时文件加重命名的方式将 `value` 序列化写入 `path`
+///
+/// A crash mid-write leaves the previous file intact instead of a truncated one.
+/// 写入过程中崩溃时会保留原文件，而不是留下被截断的文件。
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let content = serde_json::to_vec_pretty(value)?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, content)?;
+    retry::rename(&temp_path, path)
+}
+
+/// Reads a JSON-lines file, skipping lines that do not parse; a missing file reads as empty
+/// 读取 JSON-lines 文件，跳过无法解析的行；文件不存在时视为空
+pub fn read_json_lines<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut values = Vec::new();
+    for line in BufReader::new(file).lines() {
+        if let Ok(value) = serde_json::from_str(&line?) {
+            values.push(value);
+        }
+    }
+    Ok(values)
+}
+
+/// Writes `values` as JSON lines through a temporary file and a rename
+/// 通过临时文件加重命名的方式将 `values` 写为 JSON-lines
+pub fn write_json_lines<T: Serialize>(path: &Path, values: &[T]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = path.with_extension("jsonl.tmp");
+    let mut file = io::BufWriter::new(fs::File::create(&temp_path)?);
+    for value in values {
+        serde_json::to_writer(&mut file, value)?;
+        file.write_all(b"\n")?;
+    }
+    file.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+    retry::rename(&temp_path, path)
+}
@@ -0,0 +1,310 @@
+//! Application logger
+//! 应用程序日志记录器
+//!
+//! The `log_debug!`, `log_info!`, `log_warn!` and `log_error!` macros append
+//! JSON-lines records to `soarzip.log` in the app log directory in every build,
+//! and also print to the console in debug builds. Records may be tagged with an
+//! operation id (`log_info!(op: id; "...")`) so concurrent operations can be
+//! told apart. The file is rotated once it reaches `MAX_LOG_SIZE`, keeping
+//! `MAX_ROTATED_FILES` older files. Records below the runtime level set with
+//! `set_level` are dropped.
+//! `log_debug!`、`log_info!`、`log_warn!` 和 `log_error!` 宏在所有构建中都会将
+//! JSON-lines 记录追加到应用日志目录的 `soarzip.log`，在调试构建中还会输出到控制台。
+//! 记录可以带有操作 ID（`log_info!(op: id; "...")`），以便区分并发的操作。
+//! 文件达到 `MAX_LOG_SIZE` 后会轮转，并保留 `MAX_ROTATED_FILES` 个旧文件。
+//! 低于通过 `set_level` 设置的运行时级别的记录会被丢弃。
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::log::LogEntry;
+
+/// Name of the active log file
+/// 当前日志文件的名称
+pub const LOG_FILE_NAME: &str = "soarzip.log";
+
+/// Size at which the active log file is rotated
+/// 当前日志文件触发轮转的大小
+const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+
+/// Number of rotated files kept (`soarzip.1.log` is the newest)
+/// 保留的轮转文件数量（`soarzip.1.log` 为最新）
+const MAX_ROTATED_FILES: usize = 5;
+
+/// Severity of a log record
+/// 日志记录的严重级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        })
+    }
+}
+
+struct LogFile {
+    dir: PathBuf,
+    file: File,
+    size: u64,
+}
+
+static LOG_FILE: OnceLock<Mutex<LogFile>> = OnceLock::new();
+
+/// Minimum level written, stored as the `Level` discriminant
+/// 写入的最低级别，以 `Level` 的判别值存储
+static MIN_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+/// Changes the minimum level written; takes effect immediately
+/// 修改写入的最低级别；立即生效
+pub fn set_level(level: Level) {
+    MIN_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns the minimum level currently written
+/// 返回当前写入的最低级别
+pub fn level() -> Level {
+    match MIN_LEVEL.load(Ordering::Relaxed) {
+        0 => Level::Debug,
+        1 => Level::Info,
+        2 => Level::Warn,
+        _ => Level::Error,
+    }
+}
+
+/// Opens `soarzip.log` in `log_dir`; records written before this only reach the console
+/// 在 `log_dir` 中打开 `soarzip.log`；在此之前写入的记录只会输出到控制台
+pub fn init(log_dir: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(log_dir)?;
+    let file = open_append(&log_dir.join(LOG_FILE_NAME))?;
+    let size = file.metadata()?.len();
+    let _ = LOG_FILE.set(Mutex::new(LogFile {
+        dir: log_dir.to_path_buf(),
+        file,
+        size,
+    }));
+    Ok(())
+}
+
+/// Path of the active log file, once the logger is initialized
+/// 日志记录器初始化后当前日志文件的路径
+pub fn log_file_path() -> Option<PathBuf> {
+    LOG_FILE.get().map(|log| {
+        log.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .dir
+            .join(LOG_FILE_NAME)
+    })
+}
+
+/// Writes one record; use the `log_*!` macros instead of calling this directly
+/// 写入一条记录；请使用 `log_*!` 宏而不是直接调用此函数
+pub fn write(level: Level, module: &str, operation_id: Option<&str>, args: fmt::Arguments<'_>) {
+    if level < self::level() {
+        return;
+    }
+
+    let entry = LogEntry {
+        timestamp: chrono::Local::now()
+            .format("%Y-%m-%d %H:%M:%S%.3f")
+            .to_string(),
+        level,
+        module: module.to_string(),
+        operation_id: operation_id.map(str::to_string),
+        message: args.to_string(),
+    };
+
+    #[cfg(debug_assertions)]
+    match &entry.operation_id {
+        Some(id) => eprintln!(
+            "{} [{level}] {module} ({id}): {}",
+            entry.timestamp, entry.message
+        ),
+        None => eprintln!("{} [{level}] {module}: {}", entry.timestamp, entry.message),
+    }
+
+    let Ok(mut line) = serde_json::to_string(&entry) else {
+        return;
+    };
+    line.push('\n');
+
+    let Some(log) = LOG_FILE.get() else {
+        return;
+    };
+    let mut log = log.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if log.size + line.len() as u64 > MAX_LOG_SIZE {
+        rotate(&mut log);
+    }
+    if log.file.write_all(line.as_bytes()).is_ok() {
+        log.size += line.len() as u64;
+    }
+}
+
+/// Returns up to `limit` of the newest records, oldest first
+/// 返回最多 `limit` 条最新记录，按时间从旧到新排列
+///
+/// Reads the active file and, when it holds too few matches, the newest
+/// rotated one. Lines that are not valid records are skipped.
+/// 读取当前文件，匹配记录不足时再读取最新的轮转文件。无效的行会被跳过。
+///
+/// # Arguments
+/// * `operation_id` - Only return records tagged with this operation
+///   仅返回带有此操作 ID 的记录
+/// * `limit` - Maximum number of records returned
+///   返回记录的最大数量
+pub fn recent_entries(operation_id: Option<&str>, limit: usize) -> std::io::Result<Vec<LogEntry>> {
+    let Some(log) = LOG_FILE.get() else {
+        return Ok(Vec::new());
+    };
+    let dir = log
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .dir
+        .clone();
+
+    let mut entries = Vec::new();
+    for path in [dir.join(LOG_FILE_NAME), dir.join("soarzip.1.log")] {
+        if entries.len() >= limit {
+            break;
+        }
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        let matching: Vec<LogEntry> = BufReader::new(file)
+            .lines()
+            .map_while(Result::ok)
+            .filter_map(|line| serde_json::from_str::<LogEntry>(&line).ok())
+            .filter(|entry| operation_id.is_none_or(|id| entry.operation_id.as_deref() == Some(id)))
+            .collect();
+        entries.extend(matching.into_iter().rev().take(limit - entries.len()));
+    }
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Shifts `soarzip.N.log` up by one, dropping the oldest, and starts a fresh file
+/// 将 `soarzip.N.log` 依次后移一位并丢弃最旧的文件，然后开始新文件
+fn rotate(log: &mut LogFile) {
+    let rotated = |index: usize| log.dir.join(format!("soarzip.{index}.log"));
+
+    let _ = fs::remove_file(rotated(MAX_ROTATED_FILES));
+    for index in (1..MAX_ROTATED_FILES).rev() {
+        let _ = fs::rename(rotated(index), rotated(index + 1));
+    }
+    let active = log.dir.join(LOG_FILE_NAME);
+    let _ = fs::rename(&active, rotated(1));
+
+    if let Ok(file) = open_append(&active) {
+        log.file = file;
+        log.size = 0;
+    }
+}
+
+fn open_append(path: &Path) -> std::io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// Logs diagnostic detail, only written when verbose logging is on
+/// 记录诊断细节，仅在开启详细日志时写入
+macro_rules! log_debug {
+    (op: $op:expr; $($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Debug,
+            module_path!(),
+            Some($op),
+            format_args!($($arg)*),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Debug,
+            module_path!(),
+            None,
+            format_args!($($arg)*),
+        )
+    };
+}
+
+/// Logs an informational message
+/// 记录一条信息消息
+macro_rules! log_info {
+    (op: $op:expr; $($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Info,
+            module_path!(),
+            Some($op),
+            format_args!($($arg)*),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Info,
+            module_path!(),
+            None,
+            format_args!($($arg)*),
+        )
+    };
+}
+
+/// Logs a recoverable problem
+/// 记录一个可恢复的问题
+macro_rules! log_warn {
+    (op: $op:expr; $($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Warn,
+            module_path!(),
+            Some($op),
+            format_args!($($arg)*),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Warn,
+            module_path!(),
+            None,
+            format_args!($($arg)*),
+        )
+    };
+}
+
+/// Logs a failure
+/// 记录一次失败
+macro_rules! log_error {
+    (op: $op:expr; $($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Error,
+            module_path!(),
+            Some($op),
+            format_args!($($arg)*),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::utils::logger::write(
+            $crate::utils::logger::Level::Error,
+            module_path!(),
+            None,
+            format_args!($($arg)*),
+        )
+    };
+}
+
+pub(crate) use {log_debug, log_error, log_info, log_warn};
@@ -0,0 +1,13 @@
+//! Small helpers shared across services
+//! 服务之间共享的小型工具函数
+
+pub mod archive_format;
+pub mod archive_health;
+pub mod json_file;
+pub mod logger;
+pub mod paths;
+pub mod progress;
+pub mod retry;
+pub mod storage;
+pub mod temp_dir;
+pub mod time;
@@ -0,0 +1,327 @@
+//! Path helpers for local, UNC and network locations
+//! 适用于本地、UNC 和网络位置的路径辅助函数
+//!
+//! Canonicalizing on Windows yields verbatim paths (`\\?\C:\...`,
+//! `\\?\UNC\server\share\...`) that Explorer and many external programs reject,
+//! so paths handed to them go through `display_form` first.
+//! 在 Windows 上规范化路径会得到 verbatim 路径（`\\?\C:\...`、`\\?\UNC\server\share\...`），
+//! Explorer 和许多外部程序无法识别这种形式，因此传给它们的路径需先经过 `display_form`。
+
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Component, Path, PathBuf, Prefix};
+
+use crate::models::error::AppError;
+use crate::utils::logger::log_debug;
+
+/// Longest path the non-verbatim Win32 APIs accept
+/// 非 verbatim 形式的 Win32 API 所能接受的最长路径
+const MAX_DISPLAY_PATH: usize = 259;
+
+/// Converts a verbatim Windows path to its everyday form, e.g. `\\?\UNC\nas\share` to `\\nas\share`
+/// 将 verbatim 形式的 Windows 路径转换为常规形式，例如将 `\\?\UNC\nas\share` 转换为 `\\nas\share`
+///
+/// Paths that are too long for the everyday form keep their prefix, and other
+/// paths are returned unchanged.
+/// 过长而无法使用常规形式的路径会保留前缀，其他路径原样返回。
+pub fn display_form(path: &Path) -> PathBuf {
+    let mut components = path.components();
+    let Some(Component::Prefix(prefix)) = components.next() else {
+        return path.to_path_buf();
+    };
+
+    let mut display = match prefix.kind() {
+        Prefix::VerbatimUNC(server, share) => {
+            let mut root = OsString::from(r"\\");
+            root.push(server);
+            root.push(r"\");
+            root.push(share);
+            PathBuf::from(root)
+        }
+        Prefix::VerbatimDisk(disk) => PathBuf::from(format!("{}:", disk as char)),
+        _ => return path.to_path_buf(),
+    };
+    display.push(components.as_path());
+
+    if display.as_os_str().len() > MAX_DISPLAY_PATH {
+        return path.to_path_buf();
+    }
+    display
+}
+
+/// Whether `path` points at a network share, as `\\server\share` in either form or a mapped drive
+/// `path` 是否指向网络共享，包括任一形式的 `\\server\share` 和映射的网络驱动器
+pub fn is_network_path(path: &Path) -> bool {
+    let Some(Component::Prefix(prefix)) = path.components().next() else {
+        return false;
+    };
+    match prefix.kind() {
+        Prefix::UNC(..) | Prefix::VerbatimUNC(..) => true,
+        Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => is_remote_drive(letter),
+        _ => false,
+    }
+}
+
+#[cfg(windows)]
+fn is_remote_drive(letter: u8) -> bool {
+    use windows_sys::Win32::Storage::FileSystem::GetDriveTypeW;
+    use windows_sys::Win32::System::WindowsProgramming::DRIVE_REMOTE;
+
+    let root: Vec<u16> = format!("{}:\\", letter as char)
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect();
+    // SAFETY: `root` is a NUL-terminated UTF-16 string that outlives the call
+    unsafe { GetDriveTypeW(root.as_ptr()) == DRIVE_REMOTE }
+}
+
+#[cfg(not(windows))]
+fn is_remote_drive(_letter: u8) -> bool {
+    false
+}
+
+/// Folder to ask for free space when something will be written to `path`
+/// 向 `path` 写入内容时用于查询空闲空间的文件夹
+///
+/// Shares are queried at their root, which every server answers for. Local
+/// targets that don't exist yet are queried at their nearest existing ancestor.
+/// 网络共享在其根目录查询，所有服务器都支持这种查询。尚不存在的本地目标在其最近的已存在上级目录查询。
+pub fn space_query_root(path: &Path) -> PathBuf {
+    if is_network_path(path) {
+        let root: PathBuf = path
+            .components()
+            .take_while(|component| matches!(component, Component::Prefix(_) | Component::RootDir))
+            .collect();
+        return display_form(&root);
+    }
+
+    let existing = path
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .unwrap_or(path);
+    display_form(existing)
+}
+
+/// Turns a path typed or sent by the frontend into the absolute path commands work with
+/// 将前端输入或发送的路径转换为命令实际使用的绝对路径
+///
+/// Surrounding whitespace and double quotes (as left by "Copy as path") are
+/// removed, `~` expands to the home folder, relative paths resolve against
+/// `base` (a bundled app's working directory is meaningless), and paths that
+/// exist are canonicalized. Empty paths, and relative ones without a `base`,
+/// are rejected.
+/// 去除两端的空白和双引号（“复制为路径”会带上引号），`~` 展开为主文件夹，
+/// 相对路径基于 `base` 解析（打包应用的工作目录没有意义），已存在的路径会被规范化。
+/// 空路径以及没有 `base` 的相对路径会被拒绝。
+///
+/// # Arguments
+/// * `name` - Argument name reported in errors
+///   错误中报告的参数名
+/// * `raw` - Path as received
+///   收到的原始路径
+/// * `base` - Folder relative paths resolve against, usually the archive's folder
+///   相对路径的解析基准文件夹，通常为压缩包所在文件夹
+pub fn resolve_user_path(name: &str, raw: &str, base: Option<&Path>) -> Result<PathBuf, AppError> {
+    let invalid = |message: &str| AppError::InvalidArgument {
+        name: name.to_string(),
+        message: message.to_string(),
+    };
+
+    let trimmed = raw.trim();
+    let unquoted = trimmed
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .unwrap_or(trimmed)
+        .trim();
+    if unquoted.is_empty() {
+        return Err(invalid("path is empty"));
+    }
+
+    let expanded = match unquoted
+        .strip_prefix('~')
+        .filter(|rest| rest.is_empty() || rest.starts_with(['/', '\\']))
+    {
+        Some(rest) => {
+            let home = std::env::home_dir().ok_or_else(|| invalid("home folder is unknown"))?;
+            home.join(rest.trim_start_matches(['/', '\\']))
+        }
+        None => PathBuf::from(unquoted),
+    };
+
+    let absolute = if expanded.is_absolute() {
+        expanded
+    } else {
+        base.ok_or_else(|| invalid("must be an absolute path"))?
+            .join(expanded)
+    };
+
+    let resolved = match fs::canonicalize(&absolute) {
+        Ok(canonical) => display_form(&canonical),
+        Err(_) => lexically_normalize(&absolute),
+    };
+    if resolved.as_os_str() != raw {
+        log_debug!("Resolved {name} {raw:?} to {}", resolved.display());
+    }
+    Ok(resolved)
+}
+
+/// Drops `.` components and folds `..` into its parent without touching the disk
+/// 在不访问磁盘的情况下去掉 `.` 组件并将 `..` 与其上级合并
+///
+/// `..` at the root stays at the root, as it does when the path is opened.
+/// 位于根目录的 `..` 仍停留在根目录，与打开该路径时的行为一致。
+fn lexically_normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => normalized.push(component),
+            },
+            _ => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_drops_current_dir_components() {
+        assert_eq!(
+            lexically_normalize(Path::new("/data/./out/./files")),
+            Path::new("/data/out/files")
+        );
+    }
+
+    #[test]
+    fn normalize_folds_parent_dir_components() {
+        assert_eq!(
+            lexically_normalize(Path::new("/data/out/../extracted/x/..")),
+            Path::new("/data/extracted")
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_parent_dir_at_the_root() {
+        assert_eq!(lexically_normalize(Path::new("/../..")), Path::new("/"));
+        assert_eq!(
+            lexically_normalize(Path::new("/../data")),
+            Path::new("/data")
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_leading_parent_dirs_of_relative_paths() {
+        assert_eq!(
+            lexically_normalize(Path::new("../../out/..")),
+            Path::new("../..")
+        );
+    }
+
+    #[test]
+    fn space_query_root_uses_nearest_existing_ancestor() {
+        let base = std::env::temp_dir();
+        let missing = base.join(format!("soarzip-missing-{}", std::process::id()));
+        assert_eq!(space_query_root(&missing.join("a").join("b")), base);
+    }
+
+    #[test]
+    fn space_query_root_keeps_existing_folder() {
+        let base = std::env::temp_dir();
+        assert_eq!(space_query_root(&base), base);
+    }
+
+    #[test]
+    fn resolve_rejects_empty_and_unanchored_paths() {
+        assert!(resolve_user_path("path", "  ", None).is_err());
+        assert!(resolve_user_path("path", "out", None).is_err());
+        assert!(resolve_user_path("path", "\" \"", None).is_err());
+    }
+
+    #[test]
+    fn resolve_strips_quotes_and_trailing_separators() {
+        let plain = std::env::temp_dir().join("soarzip-missing-quoted.7z");
+        let quoted = format!("  \"{}\" ", plain.display());
+        let trailing = format!("{}{}", plain.display(), std::path::MAIN_SEPARATOR);
+        assert_eq!(resolve_user_path("path", &quoted, None).unwrap(), plain);
+        assert_eq!(resolve_user_path("path", &trailing, None).unwrap(), plain);
+    }
+
+    #[test]
+    fn resolve_joins_relative_paths_to_base() {
+        let base = Path::new("/soarzip-missing-base");
+        let resolved = resolve_user_path("path", "out/./x/../y", Some(base)).unwrap();
+        assert_eq!(resolved, base.join("out").join("y"));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn unix_paths_are_not_network_paths() {
+        assert!(!is_network_path(Path::new("/mnt/share/backup.7z")));
+        assert_eq!(
+            display_form(Path::new("/mnt/share/backup.7z")),
+            Path::new("/mnt/share/backup.7z")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn display_form_strips_verbatim_disk_prefix() {
+        assert_eq!(
+            display_form(Path::new(r"\\?\C:\Users\me\backup.7z")),
+            Path::new(r"C:\Users\me\backup.7z")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn display_form_strips_verbatim_unc_prefix() {
+        assert_eq!(
+            display_form(Path::new(r"\\?\UNC\nas\share\backup.7z")),
+            Path::new(r"\\nas\share\backup.7z")
+        );
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn display_form_keeps_everyday_and_overlong_paths() {
+        let everyday = Path::new(r"\\nas\share\backup.7z");
+        assert_eq!(display_form(everyday), everyday);
+
+        let overlong = PathBuf::from(format!(r"\\?\C:\{}", "a".repeat(300)));
+        assert_eq!(display_form(&overlong), overlong);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn unc_paths_are_network_paths() {
+        assert!(is_network_path(Path::new(r"\\nas\share\backup.7z")));
+        assert!(is_network_path(Path::new(r"\\?\UNC\nas\share\backup.7z")));
+        assert!(is_network_path(Path::new(r"\\localhost\c$\Windows")));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn local_system_drive_is_not_a_network_path() {
+        let system_drive = std::env::var("SystemDrive").unwrap_or_else(|_| "C:".to_string());
+        assert!(!is_network_path(Path::new(&format!(
+            r"{system_drive}\Windows"
+        ))));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn space_query_root_of_share_is_share_root() {
+        assert_eq!(
+            space_query_root(Path::new(r"\\?\UNC\nas\share\backups\new")),
+            Path::new(r"\\nas\share\")
+        );
+    }
+}
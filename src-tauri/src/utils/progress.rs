@@ -0,0 +1,219 @@
+//! Progress tracking shared by long-running operations
+//! 长时间运行的操作共用的进度跟踪
+//!
+//! Every operation feeds its byte counter into a `ProgressTracker`, so speed
+//! and ETA are computed the same way everywhere.
+//! 每个操作都将其字节计数交给 `ProgressTracker`，因此速度和预计剩余时间在各处的计算方式一致。
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::models::metrics::OperationMetrics;
+use crate::models::progress::ProgressSnapshot;
+use crate::utils::time;
+
+/// Span of samples throughput is averaged over
+/// 计算平均吞吐量所用的采样时间跨度
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(5);
+
+/// Shortest sample span a throughput is reported for; earlier figures are noise
+/// 报告吞吐量所需的最短采样跨度；更早的数值只是噪声
+const MIN_THROUGHPUT_SPAN: Duration = Duration::from_secs(1);
+
+/// Turns successive byte counts into progress snapshots with a sliding-window throughput
+/// 将连续的字节计数转换为带有滑动窗口吞吐量的进度快照
+#[derive(Debug)]
+pub struct ProgressTracker {
+    total_bytes: u64,
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl ProgressTracker {
+    /// Starts tracking an operation expected to process `total_bytes`, 0 when unknown
+    /// 开始跟踪一个预计处理 `total_bytes` 字节的操作，未知时为 0
+    pub fn new(total_bytes: u64) -> Self {
+        Self::started_at(total_bytes, Instant::now())
+    }
+
+    fn started_at(total_bytes: u64, start: Instant) -> Self {
+        Self {
+            total_bytes,
+            samples: VecDeque::from([(start, 0)]),
+        }
+    }
+
+    /// Records that `processed_bytes` have been processed in total and returns a snapshot
+    /// 记录累计已处理 `processed_bytes` 字节，并返回快照
+    pub fn update(&mut self, processed_bytes: u64) -> ProgressSnapshot {
+        self.update_at(processed_bytes, Instant::now())
+    }
+
+    fn update_at(&mut self, processed_bytes: u64, now: Instant) -> ProgressSnapshot {
+        self.samples.push_back((now, processed_bytes));
+        // Keep one sample older than the window so the span can cover all of it
+        while self.samples.len() > 2
+            && self
+                .samples
+                .get(1)
+                .is_some_and(|(at, _)| now.duration_since(*at) >= THROUGHPUT_WINDOW)
+        {
+            self.samples.pop_front();
+        }
+
+        let bytes_per_second = self.samples.front().and_then(|&(oldest_at, oldest_bytes)| {
+            let span = now.duration_since(oldest_at);
+            (span >= MIN_THROUGHPUT_SPAN).then(|| {
+                (processed_bytes.saturating_sub(oldest_bytes) as f64 / span.as_secs_f64()) as u64
+            })
+        });
+
+        let known_total = (self.total_bytes > 0).then_some(self.total_bytes);
+        let percent =
+            known_total.map(|total| (processed_bytes as f64 / total as f64 * 100.0).min(100.0));
+        let eta_seconds = known_total
+            .zip(bytes_per_second.filter(|&speed| speed > 0))
+            .map(|(total, speed)| total.saturating_sub(processed_bytes).div_ceil(speed));
+
+        ProgressSnapshot {
+            processed_bytes,
+            total_bytes: self.total_bytes,
+            percent,
+            bytes_per_second,
+            eta_seconds,
+        }
+    }
+}
+
+/// Measures an operation from start to finish for its `OperationMetrics`
+/// 从开始到结束测量一个操作，用于生成其 `OperationMetrics`
+#[derive(Debug, Clone, Copy)]
+pub struct OperationTimer {
+    started: Instant,
+    started_at: u64,
+}
+
+impl OperationTimer {
+    /// Starts timing now
+    /// 从现在开始计时
+    pub fn start() -> Self {
+        Self {
+            started: Instant::now(),
+            started_at: time::unix_now(),
+        }
+    }
+
+    /// Time since `start`
+    /// 自 `start` 以来经过的时间
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Builds the metrics of an operation ending now
+    /// 为现在结束的操作生成指标
+    pub fn finish(
+        &self,
+        bytes_processed: Option<u64>,
+        entries_processed: Option<u64>,
+    ) -> OperationMetrics {
+        OperationMetrics {
+            started_at: self.started_at,
+            duration_ms: self.elapsed().as_millis() as u64,
+            bytes_processed,
+            entries_processed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIB: u64 = 1024 * 1024;
+
+    fn secs(value: f64) -> Duration {
+        Duration::from_secs_f64(value)
+    }
+
+    #[test]
+    fn no_throughput_or_eta_before_min_span() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::started_at(100 * MIB, start);
+
+        let snapshot = tracker.update_at(10 * MIB, start);
+        assert_eq!(snapshot.bytes_per_second, None);
+        assert_eq!(snapshot.eta_seconds, None);
+
+        let snapshot = tracker.update_at(20 * MIB, start + secs(0.5));
+        assert_eq!(snapshot.bytes_per_second, None);
+        assert_eq!(snapshot.eta_seconds, None);
+        assert_eq!(snapshot.percent, Some(20.0));
+
+        let snapshot = tracker.update_at(40 * MIB, start + MIN_THROUGHPUT_SPAN);
+        assert_eq!(snapshot.bytes_per_second, Some(40 * MIB));
+        assert_eq!(snapshot.eta_seconds, Some(2));
+    }
+
+    #[test]
+    fn throughput_only_covers_the_sliding_window() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::started_at(0, start);
+
+        // 100 MiB/s for 10 s, then 10 MiB/s
+        for second in 1..=10 {
+            tracker.update_at(second * 100 * MIB, start + secs(second as f64));
+        }
+        let mut snapshot = tracker.update_at(1000 * MIB, start + secs(10.0));
+        for second in 11..=20 {
+            snapshot = tracker.update_at(
+                1000 * MIB + (second - 10) * 10 * MIB,
+                start + secs(second as f64),
+            );
+        }
+
+        // Only the last 5 s of samples (plus the one just before them) remain
+        assert_eq!(snapshot.bytes_per_second, Some(10 * MIB));
+    }
+
+    #[test]
+    fn throughput_spans_old_and_new_rates_inside_the_window() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::started_at(0, start);
+
+        tracker.update_at(300 * MIB, start + secs(3.0));
+        let snapshot = tracker.update_at(400 * MIB, start + secs(5.0));
+
+        assert_eq!(snapshot.bytes_per_second, Some(80 * MIB));
+    }
+
+    #[test]
+    fn unknown_or_zero_total_has_no_percent_or_eta() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::started_at(0, start);
+
+        let snapshot = tracker.update_at(50 * MIB, start + secs(2.0));
+        assert_eq!(snapshot.total_bytes, 0);
+        assert_eq!(snapshot.percent, None);
+        assert_eq!(snapshot.eta_seconds, None);
+        assert_eq!(snapshot.bytes_per_second, Some(25 * MIB));
+    }
+
+    #[test]
+    fn stalled_transfer_has_no_eta() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::started_at(100, start);
+
+        let snapshot = tracker.update_at(0, start + secs(3.0));
+        assert_eq!(snapshot.bytes_per_second, Some(0));
+        assert_eq!(snapshot.eta_seconds, None);
+    }
+
+    #[test]
+    fn percent_is_capped_when_more_than_the_total_is_processed() {
+        let start = Instant::now();
+        let mut tracker = ProgressTracker::started_at(100, start);
+
+        let snapshot = tracker.update_at(150, start + secs(2.0));
+        assert_eq!(snapshot.percent, Some(100.0));
+        assert_eq!(snapshot.eta_seconds, Some(0));
+    }
+}
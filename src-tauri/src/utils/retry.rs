@@ -0,0 +1,102 @@
+//! Retries for file operations blocked by transient locks
+//! 针对被临时锁定阻塞的文件操作的重试
+//!
+//! On Windows, antivirus and indexing services briefly open freshly written
+//! files, so renaming or deleting them fails with a sharing violation that
+//! clears up a moment later. Other platforms don't have mandatory locks and
+//! run the operation once.
+//! 在 Windows 上，杀毒软件和索引服务会短暂打开刚写入的文件，导致重命名或删除时出现
+//! 共享冲突，而稍后即可恢复。其他平台没有强制锁，只执行一次操作。
+
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Error kept inside an `io::Error` when a file stayed locked for the whole retry budget
+/// 当文件在整个重试时间内始终被锁定时，保存在 `io::Error` 中的错误
+#[derive(Debug)]
+pub struct FileLocked {
+    /// Time spent retrying before giving up
+    /// 放弃前用于重试的时间
+    pub retried: Duration,
+
+    /// The last sharing violation
+    /// 最后一次共享冲突
+    pub source: io::Error,
+}
+
+impl fmt::Display for FileLocked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (still locked after {} ms)",
+            self.source,
+            self.retried.as_millis()
+        )
+    }
+}
+
+impl std::error::Error for FileLocked {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Renames `from` to `to`, retrying while either is locked
+/// 将 `from` 重命名为 `to`，在任一文件被锁定时重试
+pub fn rename(from: &Path, to: &Path) -> io::Result<()> {
+    retry_locked(|| std::fs::rename(from, to))
+}
+
+/// Removes the folder `path` and its contents, retrying while a file inside is locked
+/// 删除文件夹 `path` 及其内容，在其中的文件被锁定时重试
+pub fn remove_dir_all(path: &Path) -> io::Result<()> {
+    retry_locked(|| std::fs::remove_dir_all(path))
+}
+
+/// Runs `operation` until it stops failing with a sharing violation, backing off exponentially
+/// 重复执行 `operation` 直到不再因共享冲突失败，间隔呈指数增长
+///
+/// Gives up after about five seconds and wraps the last error in [`FileLocked`].
+/// 约五秒后放弃，并将最后一个错误包装在 [`FileLocked`] 中。
+#[cfg(windows)]
+fn retry_locked<T>(mut operation: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    use std::time::Instant;
+
+    /// Delay before the first retry, doubled after each attempt
+    /// 第一次重试前的等待时间，每次尝试后加倍
+    const INITIAL_DELAY: Duration = Duration::from_millis(50);
+
+    /// Total time spent retrying before giving up
+    /// 放弃前用于重试的总时间
+    const RETRY_BUDGET: Duration = Duration::from_secs(5);
+
+    let started = Instant::now();
+    let mut delay = INITIAL_DELAY;
+    loop {
+        match operation() {
+            Err(e) if is_sharing_violation(&e) => {
+                let retried = started.elapsed();
+                if retried >= RETRY_BUDGET {
+                    return Err(io::Error::new(e.kind(), FileLocked { retried, source: e }));
+                }
+                std::thread::sleep(delay.min(RETRY_BUDGET - retried));
+                delay *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(not(windows))]
+fn retry_locked<T>(mut operation: impl FnMut() -> io::Result<T>) -> io::Result<T> {
+    operation()
+}
+
+/// `ERROR_SHARING_VIOLATION` or `ERROR_LOCK_VIOLATION`
+/// `ERROR_SHARING_VIOLATION` 或 `ERROR_LOCK_VIOLATION`
+#[cfg(windows)]
+fn is_sharing_violation(error: &io::Error) -> bool {
+    matches!(error.raw_os_error(), Some(32 | 33))
+}
@@ -0,0 +1,108 @@
+//! Storage locations for settings, data, logs, caches and staging
+//! 设置、数据、日志、缓存和暂存的存储位置
+//!
+//! Every module that writes to disk on its own behalf gets its folder from
+//! `storage_paths()`, so portable mode (a `portable.flag` file next to the
+//! executable, or `--portable`) moves everything to `data/` beside the
+//! executable at once instead of being half-applied.
+//! 每个自行写入磁盘的模块都通过 `storage_paths()` 获取其文件夹，因此便携模式
+//! （可执行文件旁的 `portable.flag` 文件或 `--portable` 参数）会一次性将所有内容移到
+//! 可执行文件旁的 `data/` 中，而不会只应用一部分。
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use tauri::{AppHandle, Manager, Runtime};
+
+/// File next to the executable that turns portable mode on
+/// 放在可执行文件旁以开启便携模式的文件
+const PORTABLE_FLAG_FILE: &str = "portable.flag";
+
+/// Command line switch that turns portable mode on
+/// 开启便携模式的命令行参数
+const PORTABLE_SWITCH: &str = "--portable";
+
+/// Folder next to the executable holding everything in portable mode
+/// 便携模式下存放所有内容的可执行文件旁文件夹
+const PORTABLE_DATA_DIR: &str = "data";
+
+/// Subfolder of the system temp directory used as the default staging root
+/// 用作默认暂存根目录的系统临时目录子文件夹
+const DEFAULT_TEMP_SUBDIR: &str = "soarzip";
+
+static STORAGE_PATHS: OnceLock<StoragePaths> = OnceLock::new();
+
+/// Folders SoarZip writes to, resolved once at startup
+/// SoarZip 写入的文件夹，在启动时解析一次
+#[derive(Debug, Clone)]
+pub struct StoragePaths {
+    /// Everything is stored next to the executable
+    /// 所有内容都存放在可执行文件旁
+    pub portable: bool,
+
+    /// Folder holding settings.json
+    /// 存放 settings.json 的文件夹
+    pub config_dir: PathBuf,
+
+    /// Folder holding the recent list and operation history
+    /// 存放最近列表和操作历史的文件夹
+    pub data_dir: PathBuf,
+
+    /// Folder holding log files
+    /// 存放日志文件的文件夹
+    pub log_dir: PathBuf,
+
+    /// Folder holding caches
+    /// 存放缓存的文件夹
+    pub cache_dir: PathBuf,
+
+    /// Staging root used when `temp_dir_override` is not set
+    /// 未设置 `temp_dir_override` 时使用的暂存根目录
+    pub temp_dir: PathBuf,
+}
+
+/// Resolves the storage folders; must run first in setup, before anything touches the disk
+/// 解析存储文件夹；必须在 setup 中最先执行，早于任何磁盘访问
+pub fn init<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    let portable_root = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        .filter(|exe_dir| {
+            exe_dir.join(PORTABLE_FLAG_FILE).is_file()
+                || args.iter().skip(1).any(|arg| arg == PORTABLE_SWITCH)
+        })
+        .map(|exe_dir| exe_dir.join(PORTABLE_DATA_DIR));
+
+    let paths = match portable_root {
+        Some(root) => StoragePaths {
+            portable: true,
+            config_dir: root.join("config"),
+            data_dir: root.join("data"),
+            log_dir: root.join("logs"),
+            cache_dir: root.join("cache"),
+            temp_dir: root.join("temp"),
+        },
+        None => {
+            let resolver = app.path();
+            StoragePaths {
+                portable: false,
+                config_dir: resolver.app_config_dir()?,
+                data_dir: resolver.app_data_dir()?,
+                log_dir: resolver.app_log_dir()?,
+                cache_dir: resolver.app_cache_dir()?,
+                temp_dir: std::env::temp_dir().join(DEFAULT_TEMP_SUBDIR),
+            }
+        }
+    };
+    let _ = STORAGE_PATHS.set(paths);
+    Ok(())
+}
+
+/// The storage folders resolved by `init`
+/// 由 `init` 解析的存储文件夹
+pub fn storage_paths() -> &'static StoragePaths {
+    STORAGE_PATHS
+        .get()
+        .expect("storage paths are resolved at the start of setup")
+}
@@ -0,0 +1,215 @@
+//! Staging directory helpers for archive operations
+//! 压缩包操作的暂存目录辅助函数
+//!
+//! Temp-heavy operations stage files under one root so users can move that
+//! traffic off a small system partition via the `temp_dir_override` setting.
+//! 大量使用临时文件的操作都在同一根目录下暂存，用户可以通过 `temp_dir_override`
+//! 设置将这部分读写移出较小的系统分区。
+//!
+//! Each operation stages into its own `op-<uuid>` folder under that root via
+//! `OpTempDir`, so concurrent operations never share files.
+//! 每个操作都通过 `OpTempDir` 在该根目录下使用自己的 `op-<uuid>` 文件夹暂存，
+//! 因此并发操作之间不会共享文件。
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::models::error::AppError;
+use crate::models::settings::AppSettings;
+use crate::utils::logger::{log_info, log_warn};
+use crate::utils::paths;
+use crate::utils::retry;
+use crate::utils::storage::storage_paths;
+
+/// Name prefix of per-operation folders; only folders with it are ever swept
+/// 单个操作文件夹的名称前缀；清理时只会删除带有此前缀的文件夹
+const OP_DIR_PREFIX: &str = "op-";
+
+/// Age after which a leftover operation folder is considered abandoned
+/// 遗留操作文件夹被视为已废弃的时长
+const STALE_OP_DIR_AGE: Duration = Duration::from_secs(3 * 24 * 60 * 60);
+
+/// A unique staging folder for one operation, removed with its contents on drop
+/// 单个操作专用的暂存文件夹，在 drop 时连同内容一起删除
+///
+/// Dropping also runs on early returns and unwinding panics, so failed
+/// operations clean up after themselves.
+/// drop 在提前返回和 panic 展开时同样会执行，因此失败的操作也会自行清理。
+#[derive(Debug)]
+pub struct OpTempDir {
+    path: PathBuf,
+}
+
+impl OpTempDir {
+    /// Creates `op-<uuid>` inside `root`, usually from `operation_temp_dir`
+    /// 在 `root` 中创建 `op-<uuid>`，`root` 通常来自 `operation_temp_dir`
+    pub fn new(root: &Path) -> io::Result<Self> {
+        let path = root.join(format!("{OP_DIR_PREFIX}{}", uuid::Uuid::new_v4()));
+        fs::create_dir(&path)?;
+        Ok(Self { path })
+    }
+
+    /// Path of the operation's staging folder
+    /// 操作暂存文件夹的路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for OpTempDir {
+    fn drop(&mut self) {
+        if let Err(e) = retry::remove_dir_all(&self.path)
+            && e.kind() != io::ErrorKind::NotFound
+        {
+            log_warn!(
+                "Failed to remove staging folder {}: {e}",
+                self.path.display()
+            );
+        }
+    }
+}
+
+/// Returns the staging root for archive operations, creating it if needed
+/// 返回压缩包操作的暂存根目录，必要时创建
+///
+/// A configured override must already exist and be writable; the default
+/// `<system temp>/soarzip` folder (`data/temp` in portable mode) is created on demand.
+/// 配置的覆盖目录必须已存在且可写；默认的 `<系统临时目录>/soarzip` 文件夹
+/// （便携模式下为 `data/temp`）会按需创建。
+pub fn operation_temp_dir(settings: &AppSettings) -> Result<PathBuf, AppError> {
+    let dir = staging_root(settings);
+    if settings.temp_dir_override.is_some() {
+        if !dir.is_dir() {
+            return Err(AppError::TempDirUnavailable {
+                path: dir.display().to_string(),
+                message: "directory does not exist".to_string(),
+            });
+        }
+    } else {
+        fs::create_dir_all(&dir).map_err(|e| AppError::TempDirUnavailable {
+            path: dir.display().to_string(),
+            message: e.to_string(),
+        })?;
+    }
+
+    probe_writable(&dir).map_err(|e| AppError::TempDirUnavailable {
+        path: dir.display().to_string(),
+        message: e.to_string(),
+    })?;
+    Ok(dir)
+}
+
+/// Returns the free space available to the current user on the volume holding `path`
+/// 返回 `path` 所在卷上当前用户可用的空闲空间
+///
+/// `path` does not need to exist yet; see [`paths::space_query_root`].
+/// `path` 不必已存在；参见 [`paths::space_query_root`]。
+pub fn available_space(path: &Path) -> Result<u64, AppError> {
+    let root = paths::space_query_root(path);
+    fs4::available_space(&root).map_err(|e| AppError::io(&root, e))
+}
+
+/// Lists the `op-*` folders under the staging root with the size of their contents
+/// 列出暂存根目录下的 `op-*` 文件夹及其内容大小
+///
+/// Only reads the staging root; it is neither created nor probed.
+/// 只读取暂存根目录，既不会创建也不会探测。
+pub fn op_dir_usage(settings: &AppSettings) -> Vec<(PathBuf, u64)> {
+    let Ok(entries) = fs::read_dir(staging_root(settings)) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .starts_with(OP_DIR_PREFIX)
+                && entry.file_type().is_ok_and(|kind| kind.is_dir())
+        })
+        .map(|entry| {
+            let path = entry.path();
+            let size = dir_size(&path).unwrap_or_default();
+            (path, size)
+        })
+        .collect()
+}
+
+/// Removes operation folders left behind by crashes, older than a few days
+/// 删除崩溃后遗留的、超过数天的操作文件夹
+///
+/// Only `op-*` folders are touched, since an override root may hold other files.
+/// 只会处理 `op-*` 文件夹，因为覆盖的根目录中可能还有其他文件。
+pub fn sweep_stale_op_dirs(settings: &AppSettings) {
+    let root = match operation_temp_dir(settings) {
+        Ok(root) => root,
+        Err(e) => {
+            log_warn!("Skipping staging cleanup: {e}");
+            return;
+        }
+    };
+    let Ok(entries) = fs::read_dir(&root) else {
+        return;
+    };
+
+    let now = SystemTime::now();
+    for entry in entries.flatten() {
+        let is_op_dir = entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with(OP_DIR_PREFIX)
+            && entry.file_type().is_ok_and(|kind| kind.is_dir());
+        let stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .is_ok_and(|modified| {
+                now.duration_since(modified)
+                    .is_ok_and(|age| age > STALE_OP_DIR_AGE)
+            });
+        if !is_op_dir || !stale {
+            continue;
+        }
+
+        let path = entry.path();
+        match retry::remove_dir_all(&path) {
+            Ok(()) => log_info!("Removed stale staging folder {}", path.display()),
+            Err(e) => log_warn!(
+                "Failed to remove stale staging folder {}: {e}",
+                path.display()
+            ),
+        }
+    }
+}
+
+/// Staging root configured by `settings`, without checking or creating it
+/// `settings` 配置的暂存根目录，不做检查也不创建
+fn staging_root(settings: &AppSettings) -> PathBuf {
+    match &settings.temp_dir_override {
+        Some(dir) => PathBuf::from(dir),
+        None => storage_paths().temp_dir.clone(),
+    }
+}
+
+fn dir_size(path: &Path) -> io::Result<u64> {
+    let mut size = 0;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        size += if metadata.is_dir() {
+            dir_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
+
+/// Stages a probe file in a throwaway operation folder to prove `dir` accepts writes
+/// 在临时操作文件夹中暂存一个探测文件，以确认 `dir` 可写
+pub fn probe_writable(dir: &Path) -> io::Result<()> {
+    let probe = OpTempDir::new(dir)?;
+    fs::write(probe.path().join("probe"), b"")
+}
@@ -0,0 +1,13 @@
+//! Wall-clock time helpers
+//! 挂钟时间辅助函数
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Current time as seconds since the Unix epoch, 0 if the clock is before it
+/// 当前时间，自 Unix 纪元起的秒数；系统时钟早于纪元时为 0
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
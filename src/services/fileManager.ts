@@ -0,0 +1,23 @@
+/**
+ * File manager service
+ * 文件管理器服务
+ *
+ * Thin wrappers around the backend commands that reveal paths in the OS file manager
+ * 对在系统文件管理器中显示路径的后端命令的简单封装
+ */
+import { invoke } from "@tauri-apps/api/core";
+
+/**
+ * Reveals a file (selected) or opens a directory in the system file manager
+ * 在系统文件管理器中显示文件（选中）或打开目录
+ *
+ * Backs "Show archive in folder" in the title bar and "Show extracted files"
+ * after an extraction, which passes the returned output directory.
+ * 用于标题栏的“在文件夹中显示压缩包”以及解压完成后的“显示解压的文件”（传入返回的输出目录）。
+ *
+ * @param path - Absolute path of the archive or output directory
+ *             - 压缩包或输出目录的绝对路径
+ */
+export async function showInFolder(path: string): Promise<void> {
+  await invoke("show_in_folder", { path });
+}